@@ -1,12 +1,42 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20261018_000001_add_job_schedule;
+mod m20261018_000002_add_job_occurrence_bounds;
+mod m20261018_000003_use_timestamptz_for_job_time;
+mod m20261018_000004_add_job_misfire_policy;
+mod m20261018_000005_add_job_expires_at;
+mod m20261018_000006_create_business_calendar;
+mod m20261018_000007_add_job_jitter;
+mod m20261018_000008_add_job_options;
+mod m20261018_000009_add_job_timeout;
+mod m20261018_000010_create_job_execution;
+mod m20261018_000011_use_string_for_job_type;
+mod m20261018_000012_add_job_execution_command_output;
+mod m20261018_000013_add_job_execution_rows_affected;
+mod m20261018_000014_add_job_scheduled_at;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20261018_000001_add_job_schedule::Migration),
+            Box::new(m20261018_000002_add_job_occurrence_bounds::Migration),
+            Box::new(m20261018_000003_use_timestamptz_for_job_time::Migration),
+            Box::new(m20261018_000004_add_job_misfire_policy::Migration),
+            Box::new(m20261018_000005_add_job_expires_at::Migration),
+            Box::new(m20261018_000006_create_business_calendar::Migration),
+            Box::new(m20261018_000007_add_job_jitter::Migration),
+            Box::new(m20261018_000008_add_job_options::Migration),
+            Box::new(m20261018_000009_add_job_timeout::Migration),
+            Box::new(m20261018_000010_create_job_execution::Migration),
+            Box::new(m20261018_000011_use_string_for_job_type::Migration),
+            Box::new(m20261018_000012_add_job_execution_command_output::Migration),
+            Box::new(m20261018_000013_add_job_execution_rows_affected::Migration),
+            Box::new(m20261018_000014_add_job_scheduled_at::Migration),
+        ]
     }
 }
@@ -0,0 +1,44 @@
+use sea_orm::{DbErr, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::Table;
+use sea_orm_migration::schema::{date_time_null, integer, integer_null};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .add_column(integer(Job::Occurrences).default(0))
+                    .add_column(integer_null(Job::MaxOccurrences))
+                    .add_column(date_time_null(Job::EndsAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .drop_column(Job::Occurrences)
+                    .drop_column(Job::MaxOccurrences)
+                    .drop_column(Job::EndsAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Job {
+    Table,
+    Occurrences,
+    MaxOccurrences,
+    EndsAt,
+}
@@ -0,0 +1,40 @@
+use sea_orm::{ConnectionTrait, DbErr, DeriveMigrationName};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing values were written as UTC wall-clock times, so convert them explicitly
+        // instead of relying on the session timezone.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                ALTER TABLE job
+                    ALTER COLUMN "time" TYPE timestamptz USING "time" AT TIME ZONE 'UTC',
+                    ALTER COLUMN ends_at TYPE timestamptz USING ends_at AT TIME ZONE 'UTC'
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                ALTER TABLE job
+                    ALTER COLUMN "time" TYPE timestamp USING "time" AT TIME ZONE 'UTC',
+                    ALTER COLUMN ends_at TYPE timestamp USING ends_at AT TIME ZONE 'UTC'
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
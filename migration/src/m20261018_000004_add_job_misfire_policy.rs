@@ -0,0 +1,53 @@
+use sea_orm::{DbErr, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::{Alias, Table};
+use sea_orm_migration::schema::string_null;
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(JobStatusEnum)
+                    .add_value(Alias::new("skipped"))
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .add_column(string_null(Job::MisfirePolicy))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Postgres cannot drop a value from an enum type, so `skipped` is left in place.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .drop_column(Job::MisfirePolicy)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Job {
+    Table,
+    MisfirePolicy,
+}
+
+#[derive(DeriveIden)]
+struct JobStatusEnum;
@@ -0,0 +1,52 @@
+use sea_orm::{DbErr, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::{Alias, ColumnDef, Table};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(JobStatusEnum)
+                    .add_value(Alias::new("expired"))
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .add_column(ColumnDef::new(Job::ExpiresAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Postgres cannot drop a value from an enum type, so `expired` is left in place.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .drop_column(Job::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Job {
+    Table,
+    ExpiresAt,
+}
+
+#[derive(DeriveIden)]
+struct JobStatusEnum;
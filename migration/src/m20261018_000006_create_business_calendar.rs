@@ -0,0 +1,79 @@
+use sea_orm::{DbErr, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{Alias, ForeignKeyAction, Table, TableForeignKey};
+use sea_orm_migration::schema::{json_binary, string, string_null, timestamps};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(timestamps(
+                Table::create()
+                    .table(BusinessCalendar::Table)
+                    .if_not_exists()
+                    .col(string(BusinessCalendar::Name).primary_key())
+                    .col(json_binary(BusinessCalendar::WorkingDays))
+                    .col(json_binary(BusinessCalendar::Holidays))
+                    .col(string_null(BusinessCalendar::Timezone))
+                    .to_owned(),
+            ))
+            .await?;
+
+        // Active jobs keep their calendar (deletion is refused by the API); finished jobs lose it.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .add_column(string_null(Job::Calendar))
+                    .add_column(string_null(Job::CalendarRoll))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-job-calendar")
+                            .from_tbl(Job::Table)
+                            .from_col(Job::Calendar)
+                            .to_tbl(BusinessCalendar::Table)
+                            .to_col(BusinessCalendar::Name)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Job::Table)
+                    .drop_foreign_key(Alias::new("fk-job-calendar"))
+                    .drop_column(Job::Calendar)
+                    .drop_column(Job::CalendarRoll)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BusinessCalendar::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BusinessCalendar {
+    Table,
+    Name,
+    WorkingDays,
+    Holidays,
+    Timezone,
+}
+
+#[derive(DeriveIden)]
+enum Job {
+    Table,
+    Calendar,
+    CalendarRoll,
+}
@@ -0,0 +1,88 @@
+use sea_orm::{DbErr, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{ForeignKey, ForeignKeyAction, Index, Table};
+use sea_orm_migration::schema::{
+    big_integer_null, integer, integer_null, json_binary_null, pk_uuid, string, text_null,
+    timestamp_with_time_zone, uuid,
+};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(JobExecution::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(JobExecution::Id))
+                    .col(uuid(JobExecution::JobId))
+                    .col(integer(JobExecution::Attempt))
+                    .col(integer(JobExecution::Occurrence))
+                    .col(string(JobExecution::Executor))
+                    .col(timestamp_with_time_zone(JobExecution::StartedAt))
+                    .col(timestamp_with_time_zone(JobExecution::FinishedAt))
+                    .col(string(JobExecution::Outcome))
+                    .col(integer_null(JobExecution::StatusCode))
+                    .col(integer_null(JobExecution::KafkaPartition))
+                    .col(big_integer_null(JobExecution::KafkaOffset))
+                    .col(json_binary_null(JobExecution::ResponseHeaders))
+                    .col(text_null(JobExecution::ResponseBody))
+                    .col(text_null(JobExecution::Error))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-jobexecution-job-id")
+                            .from(JobExecution::Table, JobExecution::JobId)
+                            .to(Job::Table, Job::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-jobexecution-job-id-started-at")
+                    .table(JobExecution::Table)
+                    .col(JobExecution::JobId)
+                    .col(JobExecution::StartedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(JobExecution::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum JobExecution {
+    Table,
+    Id,
+    JobId,
+    Attempt,
+    Occurrence,
+    Executor,
+    StartedAt,
+    FinishedAt,
+    Outcome,
+    StatusCode,
+    KafkaPartition,
+    KafkaOffset,
+    ResponseHeaders,
+    ResponseBody,
+    Error,
+}
+
+#[derive(DeriveIden)]
+enum Job {
+    Table,
+    Id,
+}
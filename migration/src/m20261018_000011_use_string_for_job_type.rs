@@ -0,0 +1,37 @@
+use sea_orm::{ConnectionTrait, DbErr, DeriveMigrationName};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Job types name registered executors, so they can no longer be a closed enum.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                ALTER TABLE job ALTER COLUMN "type" TYPE varchar USING "type"::text;
+                DROP TYPE IF EXISTS schedule_type_enum;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE TYPE schedule_type_enum AS ENUM ('http', 'kafka');
+                ALTER TABLE job ALTER COLUMN "type" TYPE schedule_type_enum USING "type"::schedule_type_enum;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use sea_orm::{DbErr, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::Table;
+use sea_orm_migration::schema::{integer_null, text_null};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(JobExecution::Table)
+                    .add_column(integer_null(JobExecution::ExitCode))
+                    .add_column(text_null(JobExecution::Stdout))
+                    .add_column(text_null(JobExecution::Stderr))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(JobExecution::Table)
+                    .drop_column(JobExecution::ExitCode)
+                    .drop_column(JobExecution::Stdout)
+                    .drop_column(JobExecution::Stderr)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum JobExecution {
+    Table,
+    ExitCode,
+    Stdout,
+    Stderr,
+}
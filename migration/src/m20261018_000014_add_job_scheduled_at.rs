@@ -0,0 +1,33 @@
+use sea_orm::{ConnectionTrait, DbErr, DeriveMigrationName};
+use sea_orm_migration::{async_trait, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Retries and spreading move `time`; `scheduled_at` keeps the time the occurrence was due.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                ALTER TABLE job ADD COLUMN scheduled_at timestamptz;
+                UPDATE job SET scheduled_at = time;
+                ALTER TABLE job ALTER COLUMN scheduled_at SET NOT NULL;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE job DROP COLUMN scheduled_at;")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SaveCalendarRequest {
+    #[validate(length(min = 1))]
+    pub working_days: Vec<String>,
+    #[serde(default)]
+    pub holidays: Vec<NaiveDate>,
+    #[validate(length(min = 1))]
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalendarResponse {
+    pub name: String,
+    pub working_days: Vec<String>,
+    pub holidays: Vec<NaiveDate>,
+    pub timezone: Option<String>,
+}
+
+impl From<CalendarEntity> for CalendarResponse {
+    fn from(calendar: CalendarEntity) -> Self {
+        CalendarResponse {
+            name: calendar.name,
+            working_days: calendar
+                .working_days
+                .iter()
+                .map(|working_day| working_day.to_string().to_lowercase())
+                .collect(),
+            holidays: calendar.holidays,
+            timezone: calendar.timezone.map(|timezone| timezone.name().to_string()),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod calendar_dto;
+
+pub use calendar_dto::{CalendarResponse, SaveCalendarRequest};
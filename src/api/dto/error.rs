@@ -26,6 +26,10 @@ impl ApiError {
         Self::new("not_found", message)
     }
     
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new("conflict", message)
+    }
+    
     pub fn internal_error(message: impl Into<String>) -> Self {
         Self::new("internal_error", message)
     }
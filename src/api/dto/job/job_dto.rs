@@ -1,6 +1,9 @@
-use chrono::NaiveDateTime;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::entity::job_time_entity::JobTimeEntity;
+use chrono::{DateTime, Utc};
 use sea_orm::JsonValue;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 use validator::Validate;
 
@@ -8,10 +11,40 @@ use validator::Validate;
 pub struct CreateJobRequest {
     #[validate(length(min = 1))]
     pub target: String,
-    pub time: NaiveDateTime,
+    pub time: Option<JobTimeEntity>,
+    #[validate(length(min = 1))]
+    pub delay: Option<String>,
+    #[validate(length(min = 1))]
+    pub timezone: Option<String>,
     #[serde(rename = "type")]
     pub job_type: String,
     pub payload: JsonValue,
+    #[validate(length(min = 1))]
+    pub cron: Option<String>,
+    #[validate(range(min = 1))]
+    pub interval: Option<i64>,
+    pub ends_at: Option<JobTimeEntity>,
+    #[validate(range(min = 1))]
+    pub max_occurrences: Option<i32>,
+    #[validate(length(min = 1))]
+    pub rrule: Option<String>,
+    pub dtstart: Option<JobTimeEntity>,
+    #[serde(default)]
+    pub exdates: Vec<JobTimeEntity>,
+    #[validate(length(min = 1))]
+    pub misfire_policy: Option<String>,
+    pub expires_at: Option<JobTimeEntity>,
+    #[validate(length(min = 1))]
+    pub calendar: Option<String>,
+    #[validate(length(min = 1))]
+    pub calendar_roll: Option<String>,
+    #[validate(length(min = 1))]
+    pub jitter: Option<String>,
+    #[validate(length(min = 1))]
+    pub timeout: Option<String>,
+    /// Every other field is an executor option.
+    #[serde(flatten)]
+    pub options: serde_json::Map<String, JsonValue>,
 }
 
 #[derive(Debug, Serialize)]
@@ -20,7 +53,69 @@ pub struct CreateJobResponse {
     pub message: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct JobExecutionResponse {
+    pub id: Uuid,
+    pub attempt: i32,
+    pub occurrence: i32,
+    pub executor: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: String,
+    pub status_code: Option<i32>,
+    pub kafka_partition: Option<i32>,
+    pub kafka_offset: Option<i64>,
+    pub response_headers: Option<BTreeMap<String, String>>,
+    pub response_body: Option<String>,
+    pub exit_code: Option<i32>,
+    pub stdout: Option<String>,
+    pub stderr: Option<String>,
+    pub rows_affected: Option<i64>,
+    pub error: Option<String>,
+}
+
+impl From<JobExecutionEntity> for JobExecutionResponse {
+    fn from(execution: JobExecutionEntity) -> Self {
+        JobExecutionResponse {
+            id: execution.id,
+            attempt: execution.attempt,
+            occurrence: execution.occurrence,
+            executor: execution.executor,
+            started_at: execution.started_at,
+            finished_at: execution.finished_at,
+            outcome: execution.outcome.to_string(),
+            status_code: execution.status_code,
+            kafka_partition: execution.kafka_partition,
+            kafka_offset: execution.kafka_offset,
+            response_headers: execution.response_headers,
+            response_body: execution.response_body,
+            exit_code: execution.exit_code,
+            stdout: execution.stdout,
+            stderr: execution.stderr,
+            rows_affected: execution.rows_affected,
+            error: execution.error,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct JobExecutionsQuery {
+    /// Id of the last execution of the previous page.
+    pub after: Option<Uuid>,
+    #[serde(default = "default_executions_limit")]
+    #[validate(range(min = 1, max = 500))]
+    pub limit: u64,
+}
+
+fn default_executions_limit() -> u64 {
+    100
+}
+
 #[derive(Debug, Deserialize, Validate)]
 pub struct UpdateJobTimeRequest {
-    pub time: NaiveDateTime,
+    pub time: Option<JobTimeEntity>,
+    #[validate(length(min = 1))]
+    pub timezone: Option<String>,
+    #[validate(length(min = 1))]
+    pub postpone_by: Option<String>,
 }
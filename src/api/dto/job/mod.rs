@@ -1,3 +1,3 @@
 pub mod job_dto;
 
-pub use job_dto::{CreateJobRequest, CreateJobResponse, UpdateJobTimeRequest};
\ No newline at end of file
+pub use job_dto::{CreateJobRequest, CreateJobResponse, JobExecutionResponse, JobExecutionsQuery, UpdateJobTimeRequest};
\ No newline at end of file
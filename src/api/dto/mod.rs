@@ -1,5 +1,7 @@
 pub mod job;
+pub mod calendar;
 pub mod error;
 
-pub use job::{CreateJobRequest, CreateJobResponse, UpdateJobTimeRequest};
-pub use error::ApiError;
\ No newline at end of file
+pub use job::{CreateJobRequest, CreateJobResponse, JobExecutionResponse, JobExecutionsQuery, UpdateJobTimeRequest};
+pub use calendar::{CalendarResponse, SaveCalendarRequest};
+pub use error::ApiError;
@@ -4,15 +4,20 @@ use axum::{
     response::Json,
 };
 use log::error;
+use sea_orm::JsonValue;
 use validator::Validate;
 
 use crate::api::dto::{CreateJobRequest, CreateJobResponse, ApiError};
+use crate::domain::job::port::driven::job_executor_port::JobExecutorRegistry;
 use crate::domain::job::usecase::create_job_use_case::{CreateJobUseCase, CreateJobRequest as DomainCreateJobRequest};
 use crate::error::TempusError;
+use crate::infrastructure::persistence::calendar::calendar_repository::CalendarRepository;
 use crate::infrastructure::persistence::job::job_repository::JobRepository;
 
 pub async fn create_job(
     State(job_repository): State<JobRepository>,
+    State(calendar_repository): State<CalendarRepository>,
+    State(job_executors): State<JobExecutorRegistry>,
     Json(payload): Json<CreateJobRequest>,
 ) -> Result<Json<CreateJobResponse>, (StatusCode, Json<ApiError>)> {
     if let Err(validation_errors) = payload.validate() {
@@ -29,11 +34,27 @@ pub async fn create_job(
     let domain_request = DomainCreateJobRequest {
         target: payload.target,
         time: payload.time,
+        delay: payload.delay,
+        timezone: payload.timezone,
         job_type: payload.job_type,
         payload: payload.payload,
+        options: JsonValue::Object(payload.options),
+        cron: payload.cron,
+        interval: payload.interval,
+        ends_at: payload.ends_at,
+        max_occurrences: payload.max_occurrences,
+        rrule: payload.rrule,
+        dtstart: payload.dtstart,
+        exdates: payload.exdates,
+        misfire_policy: payload.misfire_policy,
+        expires_at: payload.expires_at,
+        calendar: payload.calendar,
+        calendar_roll: payload.calendar_roll,
+        jitter: payload.jitter,
+        timeout: payload.timeout,
     };
 
-    let create_job_use_case = CreateJobUseCase::new(job_repository);
+    let create_job_use_case = CreateJobUseCase::new(job_repository, calendar_repository, job_executors);
     
     match create_job_use_case.execute(domain_request).await {
         Ok(domain_response) => {
@@ -70,6 +91,7 @@ pub async fn create_job(
 mod tests {
     use super::*;
     use axum::extract::State;
+    use crate::domain::job::entity::job_time_entity::JobTimeEntity;
     use chrono::Utc;
     use sea_orm::JsonValue;
     use uuid::Uuid;
@@ -78,16 +100,48 @@ mod tests {
     fn test_request_conversion() {
         let api_request = CreateJobRequest {
             target: "https://example.com".to_string(),
-            time: Utc::now().naive_utc(),
+            time: Some(JobTimeEntity::Local(Utc::now().naive_utc())),
+            delay: None,
+            timezone: None,
             job_type: "http".to_string(),
             payload: JsonValue::Null,
+            cron: None,
+            interval: None,
+            ends_at: None,
+            max_occurrences: None,
+            rrule: None,
+            dtstart: None,
+            exdates: vec![],
+            misfire_policy: None,
+            expires_at: None,
+            calendar: None,
+            calendar_roll: None,
+            jitter: None,
+            timeout: None,
+            options: serde_json::Map::new(),
         };
         
         let domain_request = DomainCreateJobRequest {
             target: api_request.target.clone(),
             time: api_request.time,
+            delay: api_request.delay.clone(),
+            timezone: api_request.timezone.clone(),
             job_type: api_request.job_type.clone(),
             payload: api_request.payload.clone(),
+            options: JsonValue::Object(api_request.options.clone()),
+            cron: api_request.cron.clone(),
+            interval: api_request.interval,
+            ends_at: api_request.ends_at,
+            max_occurrences: api_request.max_occurrences,
+            rrule: api_request.rrule.clone(),
+            dtstart: api_request.dtstart,
+            exdates: api_request.exdates.clone(),
+            misfire_policy: api_request.misfire_policy.clone(),
+            expires_at: api_request.expires_at,
+            calendar: api_request.calendar.clone(),
+            calendar_roll: api_request.calendar_roll.clone(),
+            jitter: api_request.jitter.clone(),
+            timeout: api_request.timeout.clone(),
         };
         
         assert_eq!(domain_request.target, api_request.target);
@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use log::error;
+
+use crate::api::dto::ApiError;
+use crate::domain::calendar::usecase::delete_calendar_use_case::DeleteCalendarUseCase;
+use crate::error::TempusError;
+use crate::infrastructure::persistence::calendar::calendar_repository::CalendarRepository;
+
+pub async fn delete_calendar(
+    State(calendar_repository): State<CalendarRepository>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let delete_calendar_use_case = DeleteCalendarUseCase::new(calendar_repository);
+
+    match delete_calendar_use_case.execute(&name).await {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(TempusError::Validation(msg)) => {
+            error!("Validation error: {}", msg);
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiError::not_found(msg)),
+            ))
+        }
+        Err(TempusError::Conflict(msg)) => {
+            error!("Conflict: {}", msg);
+            Err((
+                StatusCode::CONFLICT,
+                Json(ApiError::conflict(msg)),
+            ))
+        }
+        Err(e) => {
+            error!("Unexpected error while deleting calendar {}: {:?}", name, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error("Failed to delete calendar")),
+            ))
+        }
+    }
+}
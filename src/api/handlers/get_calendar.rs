@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use log::error;
+
+use crate::api::dto::{ApiError, CalendarResponse};
+use crate::domain::calendar::usecase::get_calendar_use_case::GetCalendarUseCase;
+use crate::error::TempusError;
+use crate::infrastructure::persistence::calendar::calendar_repository::CalendarRepository;
+
+pub async fn get_calendar(
+    State(calendar_repository): State<CalendarRepository>,
+    Path(name): Path<String>,
+) -> Result<Json<CalendarResponse>, (StatusCode, Json<ApiError>)> {
+    let get_calendar_use_case = GetCalendarUseCase::new(calendar_repository);
+
+    match get_calendar_use_case.execute(&name).await {
+        Ok(calendar) => Ok(Json(CalendarResponse::from(calendar))),
+        Err(TempusError::Validation(msg)) => {
+            error!("Validation error: {}", msg);
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiError::not_found(msg)),
+            ))
+        }
+        Err(e) => {
+            error!("Unexpected error while fetching calendar {}: {:?}", name, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error("Failed to fetch calendar")),
+            ))
+        }
+    }
+}
+
+pub async fn list_calendars(
+    State(calendar_repository): State<CalendarRepository>,
+) -> Result<Json<Vec<CalendarResponse>>, (StatusCode, Json<ApiError>)> {
+    let get_calendar_use_case = GetCalendarUseCase::new(calendar_repository);
+
+    match get_calendar_use_case.list().await {
+        Ok(calendars) => Ok(Json(calendars.into_iter().map(CalendarResponse::from).collect())),
+        Err(e) => {
+            error!("Unexpected error while listing calendars: {:?}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error("Failed to list calendars")),
+            ))
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use log::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api::dto::{ApiError, JobExecutionResponse, JobExecutionsQuery};
+use crate::domain::job::usecase::get_job_executions_use_case::GetJobExecutionsUseCase;
+use crate::error::TempusError;
+use crate::infrastructure::persistence::job::job_execution_repository::JobExecutionRepository;
+use crate::infrastructure::persistence::job::job_repository::JobRepository;
+
+pub async fn get_job_executions(
+    State(job_repository): State<JobRepository>,
+    State(job_execution_repository): State<JobExecutionRepository>,
+    Path(job_id): Path<Uuid>,
+    Query(query): Query<JobExecutionsQuery>,
+) -> Result<Json<Vec<JobExecutionResponse>>, (StatusCode, Json<ApiError>)> {
+    if let Err(validation_errors) = query.validate() {
+        error!("Validation failed: {:?}", validation_errors);
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::validation_error(format!(
+                "Validation errors: {:?}",
+                validation_errors
+            ))),
+        ));
+    }
+
+    let get_job_executions_use_case = GetJobExecutionsUseCase::new(job_repository, job_execution_repository);
+
+    match get_job_executions_use_case.execute(job_id, query.after, query.limit).await {
+        Ok(executions) => Ok(Json(executions.into_iter().map(JobExecutionResponse::from).collect())),
+        Err(TempusError::Validation(msg)) => {
+            error!("Validation error: {}", msg);
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiError::not_found(msg)),
+            ))
+        }
+        Err(e) => {
+            error!("Unexpected error while fetching executions of job {}: {:?}", job_id, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error("Failed to fetch job executions")),
+            ))
+        }
+    }
+}
@@ -1,9 +1,17 @@
 pub mod health;
 pub mod create_job;
 pub mod delete_job;
+pub mod get_job_executions;
 pub mod update_job;
+pub mod save_calendar;
+pub mod get_calendar;
+pub mod delete_calendar;
 
 pub use health::health_check;
 pub use create_job::create_job;
 pub use delete_job::delete_job;
-pub use update_job::update_job_time;
\ No newline at end of file
+pub use get_job_executions::get_job_executions;
+pub use update_job::update_job_time;
+pub use save_calendar::save_calendar;
+pub use get_calendar::{get_calendar, list_calendars};
+pub use delete_calendar::delete_calendar;
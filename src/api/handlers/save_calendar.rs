@@ -0,0 +1,85 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use log::error;
+use validator::Validate;
+
+use crate::api::dto::{ApiError, CalendarResponse, SaveCalendarRequest};
+use crate::domain::calendar::usecase::save_calendar_use_case::{SaveCalendarRequest as DomainSaveCalendarRequest, SaveCalendarUseCase};
+use crate::error::TempusError;
+use crate::infrastructure::persistence::calendar::calendar_repository::CalendarRepository;
+
+pub async fn save_calendar(
+    State(calendar_repository): State<CalendarRepository>,
+    Path(name): Path<String>,
+    Json(payload): Json<SaveCalendarRequest>,
+) -> Result<Json<CalendarResponse>, (StatusCode, Json<ApiError>)> {
+    if let Err(validation_errors) = payload.validate() {
+        error!("Validation failed: {:?}", validation_errors);
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::validation_error(format!(
+                "Validation errors: {:?}", 
+                validation_errors
+            ))),
+        ));
+    }
+
+    let domain_request = DomainSaveCalendarRequest {
+        name,
+        working_days: payload.working_days,
+        holidays: payload.holidays,
+        timezone: payload.timezone,
+    };
+
+    let save_calendar_use_case = SaveCalendarUseCase::new(calendar_repository);
+
+    match save_calendar_use_case.execute(domain_request).await {
+        Ok(calendar) => Ok(Json(CalendarResponse::from(calendar))),
+        Err(TempusError::Validation(msg)) => {
+            error!("Validation error: {}", msg);
+            Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::bad_request(msg)),
+            ))
+        }
+        Err(TempusError::Database(db_err)) => {
+            error!("Database error while saving calendar: {:?}", db_err);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error("Failed to save calendar")),
+            ))
+        }
+        Err(e) => {
+            error!("Unexpected error while saving calendar: {:?}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::internal_error("Failed to save calendar")),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_calendar_request_validation() {
+        let valid_request = SaveCalendarRequest {
+            working_days: vec!["mon".to_string(), "tue".to_string()],
+            holidays: vec![],
+            timezone: Some("Europe/London".to_string()),
+        };
+        let invalid_request = SaveCalendarRequest {
+            working_days: vec![],
+            holidays: vec![],
+            timezone: None,
+        };
+
+        assert!(valid_request.validate().is_ok());
+        assert!(invalid_request.validate().is_err());
+    }
+}
@@ -8,6 +8,7 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::api::dto::{ApiError, UpdateJobTimeRequest};
+use crate::domain::job::entity::job_time_entity::{parse_iso8601_duration, parse_timezone};
 use crate::domain::job::usecase::update_job_time_use_case::UpdateJobTimeUseCase;
 use crate::error::TempusError;
 use crate::infrastructure::persistence::job::job_repository::JobRepository;
@@ -28,9 +29,30 @@ pub async fn update_job_time(
         ));
     }
 
+    let bad_request = |msg: String| {
+        error!("Validation error: {}", msg);
+        (StatusCode::BAD_REQUEST, Json(ApiError::bad_request(msg)))
+    };
+
+    let timezone = payload
+        .timezone
+        .as_deref()
+        .map(parse_timezone)
+        .transpose()
+        .map_err(|e| bad_request(e.to_string()))?;
+
     let update_job_time_use_case = UpdateJobTimeUseCase::new(job_repository);
-    
-    match update_job_time_use_case.execute(job_id, payload.time).await {
+
+    let result = match (payload.time, payload.postpone_by.as_deref()) {
+        (Some(time), None) => update_job_time_use_case.execute(job_id, time.resolve(timezone)).await,
+        (None, Some(postpone_by)) => {
+            let postpone_by = parse_iso8601_duration(postpone_by).map_err(|e| bad_request(e.to_string()))?;
+            update_job_time_use_case.postpone(job_id, postpone_by).await
+        }
+        _ => return Err(bad_request("Exactly one of time or postpone_by must be set".to_string())),
+    };
+
+    match result {
         Ok(()) => Ok(StatusCode::NO_CONTENT),
         Err(TempusError::Validation(msg)) => {
             error!("Validation error: {}", msg);
@@ -39,6 +61,13 @@ pub async fn update_job_time(
                 Json(ApiError::not_found(msg)),
             ))
         }
+        Err(TempusError::Conflict(msg)) => {
+            error!("Conflict: {}", msg);
+            Err((
+                StatusCode::CONFLICT,
+                Json(ApiError::conflict(msg)),
+            ))
+        }
         Err(TempusError::Database(db_err)) => {
             error!("Database error while updating job {}: {:?}", job_id, db_err);
             Err((
@@ -59,12 +88,15 @@ pub async fn update_job_time(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::job::entity::job_time_entity::JobTimeEntity;
     use chrono::Utc;
 
     #[test]
     fn test_update_request_validation() {
         let valid_request = UpdateJobTimeRequest {
-            time: Utc::now().naive_utc(),
+            time: Some(JobTimeEntity::Local(Utc::now().naive_utc())),
+            timezone: None,
+            postpone_by: None,
         };
         
         assert!(valid_request.validate().is_ok());
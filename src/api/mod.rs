@@ -1,3 +1,5 @@
 pub mod routes;
 pub mod handlers;
-pub mod dto;
\ No newline at end of file
+pub mod dto;
+pub mod server;
+pub mod state;
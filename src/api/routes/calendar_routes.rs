@@ -0,0 +1,18 @@
+use axum::{
+    routing::get,
+    Router,
+};
+
+use crate::api::handlers;
+use crate::api::state::ApiState;
+
+pub fn calendar_router() -> Router<ApiState> {
+    Router::new()
+        .route("/calendars", get(handlers::list_calendars))
+        .route(
+            "/calendars/:name",
+            get(handlers::get_calendar)
+                .put(handlers::save_calendar)
+                .delete(handlers::delete_calendar),
+        )
+}
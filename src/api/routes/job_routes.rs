@@ -1,14 +1,15 @@
 use axum::{
-    routing::{delete, patch, post},
+    routing::{delete, get, patch, post},
     Router,
 };
 
 use crate::api::handlers;
-use crate::infrastructure::persistence::job::job_repository::JobRepository;
+use crate::api::state::ApiState;
 
-pub fn job_router() -> Router<JobRepository> {
+pub fn job_router() -> Router<ApiState> {
     Router::new()
         .route("/jobs", post(handlers::create_job))
         .route("/jobs/:job_id", delete(handlers::delete_job))
         .route("/jobs/:job_id/time", patch(handlers::update_job_time))
+        .route("/jobs/:job_id/executions", get(handlers::get_job_executions))
 }
\ No newline at end of file
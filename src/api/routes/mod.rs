@@ -1,21 +1,23 @@
 pub mod health;
 pub mod job_routes;
+pub mod calendar_routes;
 
 use axum::Router;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 
-use crate::infrastructure::persistence::job::job_repository::JobRepository;
+use crate::api::state::ApiState;
 
-pub fn create_router(job_repository: JobRepository) -> Router {
+pub fn create_router(state: ApiState) -> Router {
     let health_router = health::health_router();
     
-    let job_router = job_routes::job_router()
-        .with_state(job_repository);
+    let api_router = job_routes::job_router()
+        .merge(calendar_routes::calendar_router())
+        .with_state(state);
 
     Router::new()
         .merge(health_router)
-        .merge(job_router)
+        .merge(api_router)
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
-}
\ No newline at end of file
+}
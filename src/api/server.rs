@@ -0,0 +1,59 @@
+use crate::api::routes;
+use crate::api::state::ApiState;
+use crate::config::app_config::AppConfig;
+use crate::config::connection::connect_with_retry;
+use crate::domain::job::port::driven::job_executor_port::{JobExecutor, JobExecutorRegistry};
+use crate::engine::default_job_executors;
+use crate::error::Result;
+use crate::infrastructure::persistence::calendar::calendar_repository::CalendarRepository;
+use crate::infrastructure::persistence::job::job_execution_repository::JobExecutionRepository;
+use crate::infrastructure::persistence::job::job_repository::JobRepository;
+use axum::serve;
+use log::info;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+
+pub struct TempusApi {
+    config: AppConfig,
+    job_executors: JobExecutorRegistry,
+}
+
+impl TempusApi {
+    /// Accepts the built-in job types plus the types listed in `HTTP_EXTERNAL_JOB_TYPES`.
+    pub fn new() -> Result<Self> {
+        let config = AppConfig::load()?;
+        let job_executors = config
+            .http
+            .external_job_types()
+            .fold(default_job_executors(&config)?, JobExecutorRegistry::register_external);
+        Ok(Self { config, job_executors })
+    }
+
+    /// Validates jobs of type `name` with `executor`; register the same executor with
+    /// `TempusEngine::with_executor` so the engine can deliver them.
+    pub fn with_executor(mut self, name: &str, executor: impl JobExecutor + 'static) -> Self {
+        self.job_executors = self.job_executors.register(name, executor);
+        self
+    }
+
+    pub async fn serve(self) -> Result<()> {
+        let database = connect_with_retry(&self.config).await?;
+        let state = ApiState {
+            job_repository: JobRepository::new(database.clone()),
+            calendar_repository: CalendarRepository::new(database.clone()),
+            job_execution_repository: JobExecutionRepository::new(database),
+            job_executors: self.job_executors,
+            config: self.config.clone(),
+        };
+
+        let app = routes::create_router(state);
+        let addr = SocketAddr::from(([0, 0, 0, 0], self.config.http.port));
+        let listener = TcpListener::bind(addr).await?;
+
+        info!("Tempus API listening on {}", addr);
+
+        serve(listener, app).await?;
+
+        Ok(())
+    }
+}
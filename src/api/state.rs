@@ -0,0 +1,46 @@
+use axum::extract::FromRef;
+
+use crate::config::app_config::AppConfig;
+use crate::domain::job::port::driven::job_executor_port::JobExecutorRegistry;
+use crate::infrastructure::persistence::calendar::calendar_repository::CalendarRepository;
+use crate::infrastructure::persistence::job::job_execution_repository::JobExecutionRepository;
+use crate::infrastructure::persistence::job::job_repository::JobRepository;
+
+#[derive(Clone)]
+pub struct ApiState {
+    pub job_repository: JobRepository,
+    pub calendar_repository: CalendarRepository,
+    pub job_execution_repository: JobExecutionRepository,
+    pub job_executors: JobExecutorRegistry,
+    pub config: AppConfig,
+}
+
+impl FromRef<ApiState> for JobRepository {
+    fn from_ref(state: &ApiState) -> Self {
+        state.job_repository.clone()
+    }
+}
+
+impl FromRef<ApiState> for CalendarRepository {
+    fn from_ref(state: &ApiState) -> Self {
+        state.calendar_repository.clone()
+    }
+}
+
+impl FromRef<ApiState> for JobExecutionRepository {
+    fn from_ref(state: &ApiState) -> Self {
+        state.job_execution_repository.clone()
+    }
+}
+
+impl FromRef<ApiState> for AppConfig {
+    fn from_ref(state: &ApiState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<ApiState> for JobExecutorRegistry {
+    fn from_ref(state: &ApiState) -> Self {
+        state.job_executors.clone()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::config::app_config::AppConfig;
+use crate::config::app_config::{AppConfig, DatabaseConfig, SqlDatasourceConfig};
 use crate::error::{Result, TempusError};
 use log::{error, info};
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
@@ -10,16 +10,35 @@ async fn connect(config: &AppConfig) -> Result<DatabaseConnection> {
         .or_else(|_| dotenvy::var("DATABASE_URL"))
         .map_err(|_| TempusError::Config("DATABASE_URL environment variable not set".to_string()))?;
 
-    let mut connection_options = ConnectOptions::new(database_url);
+    let mut connection_options = connect_options(database_url, &config.database);
     connection_options
         .max_connections(config.database.max_connections)
-        .min_connections(config.database.min_connections)
-        .connect_timeout(config.database.connect_timeout())
-        .acquire_timeout(config.database.acquire_timeout())
-        .idle_timeout(config.database.idle_timeout())
-        .max_lifetime(config.database.max_lifetime())
+        .min_connections(config.database.min_connections);
+
+    Database::connect(connection_options)
+        .await
+        .map_err(TempusError::Database)
+}
+
+fn connect_options(url: String, database: &DatabaseConfig) -> ConnectOptions {
+    let mut connection_options = ConnectOptions::new(url);
+    connection_options
+        .connect_timeout(database.connect_timeout())
+        .acquire_timeout(database.acquire_timeout())
+        .idle_timeout(database.idle_timeout())
+        .max_lifetime(database.max_lifetime())
         .sqlx_logging(true)
         .sqlx_logging_level(log::LevelFilter::Debug);
+    connection_options
+}
+
+/// Connects to a datasource of `sql` jobs. Unlike the Tempus database it is not retried here:
+/// a datasource that is down fails the job, which is then retried like any other.
+pub async fn connect_datasource(datasource: &SqlDatasourceConfig, database: &DatabaseConfig) -> Result<DatabaseConnection> {
+    let mut connection_options = connect_options(datasource.url.clone(), database);
+    connection_options
+        .max_connections(datasource.max_connections)
+        .min_connections(0);
 
     Database::connect(connection_options)
         .await
@@ -0,0 +1,139 @@
+use crate::domain::calendar::r#enum::calendar_enum::CalendarRoll;
+use crate::domain::job::entity::job_time_entity::resolve_local;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::persistence::calendar::business_calendar;
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// Rolling gives up after this many days, which only happens when holidays cover every working day.
+const MAX_ROLL_DAYS: u64 = 3660;
+
+/// Working days and holidays are evaluated on the wall clock of `timezone` (UTC when unset).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEntity {
+    pub name: String,
+    pub working_days: Vec<Weekday>,
+    pub holidays: Vec<NaiveDate>,
+    pub timezone: Option<Tz>,
+}
+
+impl CalendarEntity {
+    pub fn new(
+        name: String,
+        working_days: Vec<Weekday>,
+        holidays: Vec<NaiveDate>,
+        timezone: Option<Tz>,
+    ) -> Result<Self> {
+        if working_days.is_empty() {
+            return Err(TempusError::Validation(
+                "A calendar needs at least one working day".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            name,
+            working_days,
+            holidays,
+            timezone,
+        })
+    }
+
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.working_days.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Moves `time` to the same wall-clock time on the nearest working day in the `roll` direction.
+    pub fn roll(&self, time: DateTime<Utc>, roll: CalendarRoll) -> DateTime<Utc> {
+        let timezone = self.timezone.unwrap_or(Tz::UTC);
+        let local_time = time.with_timezone(&timezone).naive_local();
+
+        (0..=MAX_ROLL_DAYS)
+            .filter_map(|days| match roll {
+                CalendarRoll::Forward => local_time.date().checked_add_days(Days::new(days)),
+                CalendarRoll::Backward => local_time.date().checked_sub_days(Days::new(days)),
+            })
+            .find(|date| self.is_working_day(*date))
+            .map(|date| {
+                if date == local_time.date() {
+                    time
+                } else {
+                    resolve_local(date.and_time(local_time.time()), timezone)
+                }
+            })
+            .unwrap_or(time)
+    }
+}
+
+impl From<business_calendar::Model> for CalendarEntity {
+    fn from(model: business_calendar::Model) -> Self {
+        CalendarEntity {
+            name: model.name,
+            working_days: serde_json::from_value(model.working_days).unwrap_or_default(),
+            holidays: serde_json::from_value(model.holidays).unwrap_or_default(),
+            timezone: model.timezone.and_then(|timezone| timezone.parse().ok()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn business_days(holidays: Vec<NaiveDate>, timezone: Option<Tz>) -> CalendarEntity {
+        CalendarEntity::new(
+            "settlement".to_string(),
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            holidays,
+            timezone,
+        )
+        .unwrap()
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn utc(month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        date(month, day).and_hms_opt(hour, 0, 0).unwrap().and_utc()
+    }
+
+    #[test]
+    fn test_new_rejects_calendar_without_working_days() {
+        assert!(CalendarEntity::new("empty".to_string(), vec![], vec![], None).is_err());
+    }
+
+    #[test]
+    fn test_is_working_day() {
+        let calendar = business_days(vec![date(12, 25)], None);
+
+        assert!(calendar.is_working_day(date(12, 24)));
+        assert!(!calendar.is_working_day(date(12, 25)));
+        assert!(!calendar.is_working_day(date(12, 27)));
+    }
+
+    #[test]
+    fn test_roll_keeps_working_days() {
+        let calendar = business_days(vec![], None);
+
+        assert_eq!(calendar.roll(utc(1, 3, 9), CalendarRoll::Forward), utc(1, 3, 9));
+        assert_eq!(calendar.roll(utc(1, 3, 9), CalendarRoll::Backward), utc(1, 3, 9));
+    }
+
+    #[test]
+    fn test_roll_over_weekend_and_holiday() {
+        let calendar = business_days(vec![date(1, 6)], None);
+
+        assert_eq!(calendar.roll(utc(1, 4, 9), CalendarRoll::Forward), utc(1, 7, 9));
+        assert_eq!(calendar.roll(utc(1, 4, 9), CalendarRoll::Backward), utc(1, 3, 9));
+        assert_eq!(calendar.roll(utc(1, 6, 9), CalendarRoll::Backward), utc(1, 3, 9));
+    }
+
+    #[test]
+    fn test_roll_uses_calendar_timezone() {
+        let calendar = business_days(vec![], Some(Tz::Asia__Tokyo));
+
+        // Friday 23:00 UTC is already Saturday 08:00 in Tokyo.
+        assert_eq!(calendar.roll(utc(1, 3, 23), CalendarRoll::Forward), utc(1, 5, 23));
+        assert_eq!(calendar.roll(utc(1, 3, 23), CalendarRoll::Backward), utc(1, 2, 23));
+    }
+}
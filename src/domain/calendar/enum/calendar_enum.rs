@@ -0,0 +1,43 @@
+use crate::error::TempusError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Direction in which a fire time landing on a non-working day is moved.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CalendarRoll {
+    #[default]
+    Forward,
+    Backward,
+}
+
+impl FromStr for CalendarRoll {
+    type Err = TempusError;
+
+    fn from_str(roll: &str) -> Result<Self, Self::Err> {
+        match roll {
+            "forward" => Ok(CalendarRoll::Forward),
+            "backward" => Ok(CalendarRoll::Backward),
+            _ => Err(TempusError::Validation(format!(
+                "Invalid calendar roll: {}. Supported rolls: forward, backward",
+                roll
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for CalendarRoll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarRoll::Forward => write!(f, "forward"),
+            CalendarRoll::Backward => write!(f, "backward"),
+        }
+    }
+}
+
+/// Outcome of deleting a calendar, which is refused while scheduled jobs use it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalendarDeletion {
+    Deleted,
+    NotFound,
+    InUse(u64),
+}
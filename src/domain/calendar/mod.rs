@@ -0,0 +1,4 @@
+pub mod usecase;
+pub mod port;
+pub mod entity;
+pub mod r#enum;
@@ -0,0 +1,14 @@
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use crate::domain::calendar::r#enum::calendar_enum::CalendarDeletion;
+use sea_orm::DbErr;
+use sea_orm::prelude::async_trait::async_trait;
+
+#[async_trait]
+pub trait CalendarRepositoryPort: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<CalendarEntity>, DbErr>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<CalendarEntity>, DbErr>;
+    async fn save(&self, calendar: &CalendarEntity) -> Result<(), DbErr>;
+    /// Counts the jobs using the calendar and deletes it in one transaction, so no job can start
+    /// using it in between.
+    async fn delete_unused(&self, name: &str) -> Result<CalendarDeletion, DbErr>;
+}
@@ -0,0 +1,32 @@
+use log::info;
+
+use crate::domain::calendar::port::driven::calendar_repository_port::CalendarRepositoryPort;
+use crate::domain::calendar::r#enum::calendar_enum::CalendarDeletion;
+use crate::error::{Result, TempusError};
+
+pub struct DeleteCalendarUseCase<R: CalendarRepositoryPort> {
+    calendar_repository: R,
+}
+
+impl<R: CalendarRepositoryPort> DeleteCalendarUseCase<R> {
+    pub fn new(calendar_repository: R) -> Self {
+        Self { calendar_repository }
+    }
+
+    pub async fn execute(&self, name: &str) -> Result<()> {
+        let deletion = self.calendar_repository.delete_unused(name).await
+            .map_err(TempusError::from)?;
+
+        match deletion {
+            CalendarDeletion::Deleted => {
+                info!("Calendar deleted successfully with name: {}", name);
+                Ok(())
+            }
+            CalendarDeletion::NotFound => Err(TempusError::Validation(format!("Calendar not found: {}", name))),
+            CalendarDeletion::InUse(active_jobs) => Err(TempusError::Conflict(format!(
+                "Calendar {} is still used by {} scheduled jobs",
+                name, active_jobs
+            ))),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use crate::domain::calendar::port::driven::calendar_repository_port::CalendarRepositoryPort;
+use crate::error::{Result, TempusError};
+
+pub struct GetCalendarUseCase<R: CalendarRepositoryPort> {
+    calendar_repository: R,
+}
+
+impl<R: CalendarRepositoryPort> GetCalendarUseCase<R> {
+    pub fn new(calendar_repository: R) -> Self {
+        Self { calendar_repository }
+    }
+
+    pub async fn execute(&self, name: &str) -> Result<CalendarEntity> {
+        self.calendar_repository.find_by_name(name).await
+            .map_err(TempusError::from)?
+            .ok_or_else(|| TempusError::Validation(format!("Calendar not found: {}", name)))
+    }
+
+    pub async fn list(&self) -> Result<Vec<CalendarEntity>> {
+        self.calendar_repository.find_all().await
+            .map_err(TempusError::from)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod save_calendar_use_case;
+pub mod get_calendar_use_case;
+pub mod delete_calendar_use_case;
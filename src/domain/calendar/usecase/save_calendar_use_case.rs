@@ -0,0 +1,48 @@
+use chrono::{NaiveDate, Weekday};
+use log::info;
+
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use crate::domain::calendar::port::driven::calendar_repository_port::CalendarRepositoryPort;
+use crate::domain::job::entity::job_time_entity::parse_timezone;
+use crate::error::{Result, TempusError};
+
+pub struct SaveCalendarUseCase<R: CalendarRepositoryPort> {
+    calendar_repository: R,
+}
+
+impl<R: CalendarRepositoryPort> SaveCalendarUseCase<R> {
+    pub fn new(calendar_repository: R) -> Self {
+        Self { calendar_repository }
+    }
+
+    pub async fn execute(&self, request: SaveCalendarRequest) -> Result<CalendarEntity> {
+        let working_days = self.parse_working_days(&request.working_days)?;
+        let timezone = request.timezone.as_deref().map(parse_timezone).transpose()?;
+        let calendar = CalendarEntity::new(request.name, working_days, request.holidays, timezone)?;
+
+        self.calendar_repository.save(&calendar).await
+            .map_err(TempusError::from)?;
+
+        info!("Calendar saved successfully with name: {}", calendar.name);
+        Ok(calendar)
+    }
+
+    fn parse_working_days(&self, working_days: &[String]) -> Result<Vec<Weekday>> {
+        working_days
+            .iter()
+            .map(|working_day| {
+                working_day.parse::<Weekday>().map_err(|_| {
+                    TempusError::Validation(format!("Invalid working day: {}", working_day))
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct SaveCalendarRequest {
+    pub name: String,
+    pub working_days: Vec<String>,
+    pub holidays: Vec<NaiveDate>,
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,122 @@
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// AMQP limits routing keys and content types to short strings.
+const SHORT_STRING_LIMIT: usize = 255;
+
+/// Message options for AMQP jobs, which publish to the exchange named by the job target.
+/// Messages are persistent unless `persistent` is turned off, and without a `content_type` they
+/// are sent as `application/json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobAmqpMessageEntity {
+    #[serde(default)]
+    pub routing_key: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default = "default_persistent")]
+    pub persistent: bool,
+    #[serde(default)]
+    pub content_type: Option<String>,
+}
+
+fn default_persistent() -> bool {
+    true
+}
+
+impl Default for JobAmqpMessageEntity {
+    fn default() -> Self {
+        Self {
+            routing_key: String::new(),
+            headers: BTreeMap::new(),
+            persistent: default_persistent(),
+            content_type: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AmqpJobOptions {
+    routing_key: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    persistent: Option<bool>,
+    content_type: Option<String>,
+}
+
+impl JobAmqpMessageEntity {
+    pub fn from_options(options: &JsonValue) -> Result<Self> {
+        let options: AmqpJobOptions = parse_options(options)?;
+
+        if options.content_type.as_ref().is_some_and(String::is_empty) {
+            return Err(TempusError::Validation("content_type cannot be empty".to_string()));
+        }
+
+        Self::new(options.routing_key, options.headers, options.persistent, options.content_type)
+    }
+
+    pub fn new(
+        routing_key: Option<String>,
+        headers: BTreeMap<String, String>,
+        persistent: Option<bool>,
+        content_type: Option<String>,
+    ) -> Result<Self> {
+        if let Some(name) = headers.keys().find(|name| name.is_empty() || name.len() > SHORT_STRING_LIMIT) {
+            return Err(TempusError::Validation(format!("Invalid header name: {:?}", name)));
+        }
+
+        let routing_key = routing_key.unwrap_or_default();
+        if routing_key.len() > SHORT_STRING_LIMIT {
+            return Err(TempusError::Validation(format!(
+                "routing_key cannot be longer than {} bytes",
+                SHORT_STRING_LIMIT
+            )));
+        }
+
+        if content_type.as_ref().is_some_and(|content_type| content_type.len() > SHORT_STRING_LIMIT) {
+            return Err(TempusError::Validation(format!(
+                "content_type cannot be longer than {} bytes",
+                SHORT_STRING_LIMIT
+            )));
+        }
+
+        Ok(Self {
+            routing_key,
+            headers,
+            persistent: persistent.unwrap_or_else(default_persistent),
+            content_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_short_strings() {
+        let headers = |name: &str| BTreeMap::from([(name.to_string(), "value".to_string())]);
+
+        assert!(JobAmqpMessageEntity::new(Some("orders.created".to_string()), headers("trace-id"), None, None).is_ok());
+        assert!(JobAmqpMessageEntity::new(None, headers(""), None, None).is_err());
+        assert!(JobAmqpMessageEntity::new(Some("k".repeat(256)), BTreeMap::new(), None, None).is_err());
+        assert!(JobAmqpMessageEntity::new(None, BTreeMap::new(), None, Some("t".repeat(256))).is_err());
+    }
+
+    #[test]
+    fn test_messages_are_persistent_by_default() {
+        let amqp_message = JobAmqpMessageEntity::new(None, BTreeMap::new(), None, None).unwrap();
+        assert!(amqp_message.persistent);
+        assert_eq!(amqp_message.routing_key, "");
+
+        let stored: JobAmqpMessageEntity =
+            serde_json::from_value(serde_json::json!({"routing_key": "orders.created"})).unwrap();
+        assert!(stored.persistent);
+
+        let transient = JobAmqpMessageEntity::new(None, BTreeMap::new(), Some(false), None).unwrap();
+        assert!(!transient.persistent);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// SQS and SNS accept 10 message attributes, two of which carry the job id and attempt.
+const MAX_ATTRIBUTES: usize = 8;
+const MAX_ATTRIBUTE_NAME_LENGTH: usize = 256;
+const MAX_ID_LENGTH: usize = 128;
+
+/// Message options for SQS and SNS jobs, which send to the queue URL or topic ARN named by the
+/// job target. Targets ending in `.fifo` are FIFO queues or topics and need a message group id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobAwsMessageEntity {
+    #[serde(default)]
+    pub attributes: BTreeMap<String, String>,
+    #[serde(default)]
+    pub message_group_id: Option<String>,
+    #[serde(default)]
+    pub message_deduplication_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AwsJobOptions {
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    message_group_id: Option<String>,
+    message_deduplication_id: Option<String>,
+}
+
+impl JobAwsMessageEntity {
+    /// The job `headers` become message attributes.
+    pub fn from_options(options: &JsonValue, target: &str) -> Result<Self> {
+        let options: AwsJobOptions = parse_options(options)?;
+        Self::new(target, options.headers, options.message_group_id, options.message_deduplication_id)
+    }
+
+    pub fn new(
+        target: &str,
+        attributes: BTreeMap<String, String>,
+        message_group_id: Option<String>,
+        message_deduplication_id: Option<String>,
+    ) -> Result<Self> {
+        if attributes.len() > MAX_ATTRIBUTES {
+            return Err(TempusError::Validation(format!(
+                "No more than {} headers are supported for sqs and sns jobs",
+                MAX_ATTRIBUTES
+            )));
+        }
+
+        if let Some(name) = attributes.keys().find(|name| !is_valid_attribute_name(name)) {
+            return Err(TempusError::Validation(format!("Invalid message attribute name: {:?}", name)));
+        }
+
+        if let Some(name) = attributes.iter().find_map(|(name, value)| value.is_empty().then_some(name)) {
+            return Err(TempusError::Validation(format!("Empty value for message attribute: {:?}", name)));
+        }
+
+        for (field, id) in [
+            ("message_group_id", &message_group_id),
+            ("message_deduplication_id", &message_deduplication_id),
+        ] {
+            if id.as_ref().is_some_and(|id| !is_valid_id(id)) {
+                return Err(TempusError::Validation(format!(
+                    "{} must be at most {} printable ASCII characters",
+                    field, MAX_ID_LENGTH
+                )));
+            }
+        }
+
+        if is_fifo(target) && message_group_id.is_none() {
+            return Err(TempusError::Validation(
+                "message_group_id is required for FIFO queues and topics".to_string(),
+            ));
+        }
+
+        if !is_fifo(target) && message_deduplication_id.is_some() {
+            return Err(TempusError::Validation(
+                "message_deduplication_id is only supported for FIFO queues and topics".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            attributes,
+            message_group_id,
+            message_deduplication_id,
+        })
+    }
+}
+
+pub fn is_fifo(target: &str) -> bool {
+    target.ends_with(".fifo")
+}
+
+fn is_valid_attribute_name(name: &str) -> bool {
+    let lowercase = name.to_lowercase();
+
+    !name.is_empty()
+        && name.len() <= MAX_ATTRIBUTE_NAME_LENGTH
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !name.starts_with('.')
+        && !name.ends_with('.')
+        && !name.contains("..")
+        && !lowercase.starts_with("aws.")
+        && !lowercase.starts_with("amazon.")
+}
+
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_ID_LENGTH && id.chars().all(|c| c.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUEUE: &str = "http://localhost:4566/000000000000/orders";
+    const FIFO_QUEUE: &str = "http://localhost:4566/000000000000/orders.fifo";
+
+    #[test]
+    fn test_new_validates_attributes() {
+        let attributes = |name: &str, value: &str| BTreeMap::from([(name.to_string(), value.to_string())]);
+
+        assert!(JobAwsMessageEntity::new(QUEUE, attributes("trace-id", "abc"), None, None).is_ok());
+        assert!(JobAwsMessageEntity::new(QUEUE, attributes("trace id", "abc"), None, None).is_err());
+        assert!(JobAwsMessageEntity::new(QUEUE, attributes("AWS.TraceId", "abc"), None, None).is_err());
+        assert!(JobAwsMessageEntity::new(QUEUE, attributes("trace..id", "abc"), None, None).is_err());
+        assert!(JobAwsMessageEntity::new(QUEUE, attributes("trace-id", ""), None, None).is_err());
+
+        let too_many = (0..=MAX_ATTRIBUTES).map(|i| (format!("a{}", i), "v".to_string())).collect();
+        assert!(JobAwsMessageEntity::new(QUEUE, too_many, None, None).is_err());
+    }
+
+    #[test]
+    fn test_new_validates_fifo_options() {
+        let group = Some("customer-1".to_string());
+        let deduplication = Some("order-1".to_string());
+
+        assert!(JobAwsMessageEntity::new(FIFO_QUEUE, BTreeMap::new(), group.clone(), deduplication.clone()).is_ok());
+        assert!(JobAwsMessageEntity::new(FIFO_QUEUE, BTreeMap::new(), None, None).is_err());
+        assert!(JobAwsMessageEntity::new(QUEUE, BTreeMap::new(), None, deduplication).is_err());
+        assert!(JobAwsMessageEntity::new(FIFO_QUEUE, BTreeMap::new(), Some("g".repeat(129)), None).is_err());
+        assert!(JobAwsMessageEntity::new(FIFO_QUEUE, BTreeMap::new(), Some("group 1".to_string()), None).is_err());
+    }
+}
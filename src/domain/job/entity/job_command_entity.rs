@@ -0,0 +1,76 @@
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// How a `command` job runs the executable named by its target, read from the job payload. The
+/// command only sees the variables in `env`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobCommandEntity {
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    #[serde(default)]
+    pub stdin: Option<String>,
+}
+
+impl JobCommandEntity {
+    pub fn from_payload(payload: &JsonValue) -> Result<Self> {
+        let command: JobCommandEntity = match payload {
+            JsonValue::Null => JobCommandEntity::default(),
+            payload => serde_json::from_value(payload.clone())
+                .map_err(|e| TempusError::Validation(format!("Invalid command payload: {}", e)))?,
+        };
+
+        if command.args.iter().chain(command.env.values()).chain(&command.stdin).any(|value| value.contains('\0')) {
+            return Err(TempusError::Validation("Command arguments, env and stdin cannot contain NUL".to_string()));
+        }
+
+        if let Some(name) = command.env.keys().find(|name| name.is_empty() || name.contains(['=', '\0'])) {
+            return Err(TempusError::Validation(format!("Invalid env variable name: {:?}", name)));
+        }
+
+        if command.working_dir.as_ref().is_some_and(|working_dir| !Path::new(working_dir).is_absolute()) {
+            return Err(TempusError::Validation("working_dir must be an absolute path".to_string()));
+        }
+
+        Ok(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_payload() {
+        let command = JobCommandEntity::from_payload(&json!({
+            "args": ["--dry-run"],
+            "env": {"RUST_LOG": "info"},
+            "working_dir": "/srv",
+            "stdin": "input"
+        }))
+        .unwrap();
+
+        assert_eq!(command.args, ["--dry-run"]);
+        assert_eq!(command.env.get("RUST_LOG").map(String::as_str), Some("info"));
+        assert_eq!(command.working_dir.as_deref(), Some("/srv"));
+        assert_eq!(command.stdin.as_deref(), Some("input"));
+        assert_eq!(JobCommandEntity::from_payload(&JsonValue::Null).unwrap(), JobCommandEntity::default());
+    }
+
+    #[test]
+    fn test_from_payload_rejects_invalid_commands() {
+        assert!(JobCommandEntity::from_payload(&json!({"argv": ["--dry-run"]})).is_err());
+        assert!(JobCommandEntity::from_payload(&json!({"args": "--dry-run"})).is_err());
+        assert!(JobCommandEntity::from_payload(&json!({"args": ["a\u{0}b"]})).is_err());
+        assert!(JobCommandEntity::from_payload(&json!({"env": {"A=B": "c"}})).is_err());
+        assert!(JobCommandEntity::from_payload(&json!({"working_dir": "relative"})).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// An `email` job read from its payload. `{{name}}` placeholders in the subject and bodies are
+/// replaced with `variables`, HTML-escaped in the `html` body.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobEmailEntity {
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub cc: Vec<String>,
+    pub subject: String,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub html: Option<String>,
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub text: Option<String>,
+    pub html: Option<String>,
+}
+
+impl JobEmailEntity {
+    pub fn from_payload(payload: &JsonValue) -> Result<Self> {
+        let email: JobEmailEntity = serde_json::from_value(payload.clone())
+            .map_err(|e| TempusError::Validation(format!("Invalid email payload: {}", e)))?;
+
+        if email.to.is_empty() {
+            return Err(TempusError::Validation("Emails need at least one to address".to_string()));
+        }
+
+        email.to.iter().chain(&email.cc).try_for_each(|address| validate_email_address(address))?;
+
+        if email.text.is_none() && email.html.is_none() {
+            return Err(TempusError::Validation("Emails need a text or html body".to_string()));
+        }
+
+        email.render()?;
+        Ok(email)
+    }
+
+    pub fn render(&self) -> Result<RenderedEmail> {
+        Ok(RenderedEmail {
+            subject: render_template(&self.subject, &self.variables, false)?,
+            text: self
+                .text
+                .as_ref()
+                .map(|text| render_template(text, &self.variables, false))
+                .transpose()?,
+            html: self
+                .html
+                .as_ref()
+                .map(|html| render_template(html, &self.variables, true))
+                .transpose()?,
+        })
+    }
+}
+
+/// A plain `local@domain` address; display names are not supported.
+pub fn validate_email_address(address: &str) -> Result<()> {
+    let valid = address
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty() && !domain.contains('@'))
+        && !address.contains(|c: char| c.is_whitespace() || c.is_control() || matches!(c, '<' | '>' | ',' | ';'));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(TempusError::Validation(format!("Invalid email address: {:?}", address)))
+    }
+}
+
+fn render_template(template: &str, variables: &BTreeMap<String, String>, escape_html: bool) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| TempusError::Validation("Unclosed template placeholder".to_string()))?;
+        let name = rest[start + 2..start + end].trim();
+        let value = variables
+            .get(name)
+            .ok_or_else(|| TempusError::Validation(format!("Unknown template variable: {}", name)))?;
+
+        rendered.push_str(&rest[..start]);
+        if escape_html {
+            rendered.push_str(&html_escape(value));
+        } else {
+            rendered.push_str(value);
+        }
+        rest = &rest[start + end + 2..];
+    }
+
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_replaces_variables() {
+        let email = JobEmailEntity::from_payload(&json!({
+            "to": ["ada@example.com"],
+            "subject": "Reminder for {{ name }}",
+            "text": "Hi {{name}}, your appointment is at {{time}}.",
+            "html": "<p>Hi {{name}}</p>",
+            "variables": {"name": "Ada <Lovelace>", "time": "10:00"}
+        }))
+        .unwrap();
+
+        assert_eq!(
+            email.render().unwrap(),
+            RenderedEmail {
+                subject: "Reminder for Ada <Lovelace>".to_string(),
+                text: Some("Hi Ada <Lovelace>, your appointment is at 10:00.".to_string()),
+                html: Some("<p>Hi Ada &lt;Lovelace&gt;</p>".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_payload_rejects_invalid_emails() {
+        let email = |payload: JsonValue| JobEmailEntity::from_payload(&payload);
+
+        assert!(email(json!({"to": [], "subject": "s", "text": "t"})).is_err());
+        assert!(email(json!({"to": ["not-an-address"], "subject": "s", "text": "t"})).is_err());
+        assert!(email(json!({"to": ["a@example.com"], "cc": ["b@example.com, c@example.com"], "subject": "s", "text": "t"})).is_err());
+        assert!(email(json!({"to": ["a@example.com"], "subject": "s"})).is_err());
+        assert!(email(json!({"to": ["a@example.com"], "subject": "{{missing}}", "text": "t"})).is_err());
+        assert!(email(json!({"to": ["a@example.com"], "subject": "{{unclosed", "text": "t"})).is_err());
+        assert!(email(json!({"to": ["a@example.com"], "subject": "s", "text": "t", "bcc": []})).is_err());
+    }
+}
@@ -1,39 +1,181 @@
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use crate::domain::calendar::r#enum::calendar_enum::CalendarRoll;
 use crate::domain::job::entity::job_metadata_entity::JobMetadataEntity;
-use crate::domain::job::r#enum::job_enum::{JobMetadataStatus, JobType};
+use crate::domain::job::entity::job_schedule_entity::JobScheduleEntity;
+use crate::domain::job::r#enum::job_enum::{JobMetadataStatus, JobType, MisfirePolicy};
+use crate::error::{Result, TempusError};
 use crate::infrastructure::persistence::job::job_metadata::Model;
-use crate::infrastructure::persistence::job::sea_orm_active_enums::{
-    JobStatusEnum, ScheduleTypeEnum,
-};
+use crate::infrastructure::persistence::job::sea_orm_active_enums::JobStatusEnum;
 use crate::infrastructure::persistence::job::{job, job_metadata};
-use chrono::NaiveDateTime;
-use sea_orm::JsonValue;
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{DbErr, JsonValue};
 use sea_orm::prelude::Uuid;
+use serde::de::DeserializeOwned;
 
 #[derive(Debug, Clone)]
 pub struct JobEntity {
     pub id: Uuid,
-    pub time: NaiveDateTime,
+    pub time: DateTime<Utc>,
+    /// When the current occurrence was due; unlike `time`, retries and spreading don't move it.
+    pub scheduled_at: DateTime<Utc>,
     pub target: String,
     pub retries: i32,
     pub r#type: JobType,
     pub payload: JsonValue,
+    /// Executor options, checked by the executor when the job is created.
+    pub options: JsonValue,
+    pub schedule: Option<JobScheduleEntity>,
+    pub occurrences: i32,
+    pub max_occurrences: Option<i32>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub misfire_policy: Option<MisfirePolicy>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub calendar: Option<CalendarEntity>,
+    pub calendar_roll: CalendarRoll,
+    pub jitter: Option<Duration>,
+    pub timeout: Option<Duration>,
     pub metadata: Option<JobMetadataEntity>,
 }
 
-impl From<(job::Model, Option<Model>)> for JobEntity {
-    fn from(tuple: (job::Model, Option<job_metadata::Model>)) -> Self {
-        let (job_model, job_metadata_model) = tuple;
+/// Deterministic pseudo-random value in `[0, 1)` derived from the job id, so jitter is
+/// reproducible. Different `salt`s give independent values for the same job.
+pub fn seeded_fraction(job_id: Uuid, salt: u64) -> f64 {
+    let id = job_id.as_u128();
+    let mut x = (id as u64) ^ ((id >> 64) as u64) ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Reads the executor options of a job being created. Executors deny unknown fields, so options
+/// meant for another job type are rejected.
+pub fn parse_options<T: DeserializeOwned>(options: &JsonValue) -> Result<T> {
+    let options = match options {
+        JsonValue::Null => JsonValue::Object(Default::default()),
+        options => options.clone(),
+    };
+
+    serde_json::from_value(options).map_err(|e| TempusError::Validation(format!("Invalid options: {}", e)))
+}
+
+impl JobEntity {
+    /// Delays `time` by a seeded offset within the job `jitter` window; `occurrence` varies the
+    /// offset between occurrences of a recurring job.
+    pub fn apply_jitter(&self, time: DateTime<Utc>, occurrence: i32) -> DateTime<Utc> {
+        match self.jitter {
+            Some(jitter) => {
+                let fraction = seeded_fraction(self.id, occurrence as u64);
+                time + Duration::milliseconds((jitter.num_milliseconds() as f64 * fraction) as i64)
+            }
+            None => time,
+        }
+    }
+
+    pub fn execution_timeout(&self) -> Option<std::time::Duration> {
+        self.timeout.and_then(|timeout| timeout.to_std().ok())
+    }
+
+    /// Decodes the executor options stored with the job, `None` when it has none.
+    pub fn options<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        if self.options.is_null() {
+            return Ok(None);
+        }
+
+        serde_json::from_value(self.options.clone())
+            .map(Some)
+            .map_err(|e| TempusError::JobProcessing(format!("Invalid options for job {}: {}", self.id, e)))
+    }
+
+    /// Moves a computed fire time off non-working days of the job calendar, if it has one.
+    pub fn roll_to_working_day(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        match &self.calendar {
+            Some(calendar) => calendar.roll(time, self.calendar_roll),
+            None => time,
+        }
+    }
+}
 
+#[cfg(test)]
+impl JobEntity {
+    /// A due one-shot job with no options, for tests to adjust with struct update syntax.
+    pub fn fixture(job_type: &str, target: &str) -> Self {
         JobEntity {
+            id: Uuid::new_v4(),
+            time: DateTime::from_timestamp(0, 0).unwrap(),
+            scheduled_at: DateTime::from_timestamp(0, 0).unwrap(),
+            target: target.to_string(),
+            retries: 0,
+            r#type: JobType::new(job_type),
+            payload: JsonValue::Null,
+            options: JsonValue::Null,
+            schedule: None,
+            occurrences: 0,
+            max_occurrences: None,
+            ends_at: None,
+            misfire_policy: None,
+            expires_at: None,
+            calendar: None,
+            calendar_roll: CalendarRoll::Forward,
+            jitter: None,
+            timeout: None,
+            metadata: None,
+        }
+    }
+}
+
+/// Fails on a stored schedule, misfire policy or calendar roll that no longer decodes, rather
+/// than loading the job as if it had none.
+impl TryFrom<(job::Model, Option<Model>)> for JobEntity {
+    type Error = DbErr;
+
+    fn try_from(tuple: (job::Model, Option<job_metadata::Model>)) -> std::result::Result<Self, DbErr> {
+        let (job_model, job_metadata_model) = tuple;
+        let invalid = |column: &str, e: &dyn std::fmt::Display| {
+            DbErr::Custom(format!("Invalid {} for job {}: {}", column, job_model.id, e))
+        };
+
+        let schedule = job_model
+            .schedule
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| invalid("schedule", &e))?;
+        let misfire_policy = job_model
+            .misfire_policy
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(|e| invalid("misfire_policy", &e))?;
+        let calendar_roll = job_model
+            .calendar_roll
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(|e| invalid("calendar_roll", &e))?
+            .unwrap_or_default();
+
+        Ok(JobEntity {
             id: job_model.id,
-            time: job_model.time,
+            time: job_model.time.with_timezone(&Utc),
+            scheduled_at: job_model.scheduled_at.with_timezone(&Utc),
             target: job_model.target,
             retries: job_model.retries,
-            r#type: match job_model.r#type {
-                ScheduleTypeEnum::Http => JobType::Http,
-                ScheduleTypeEnum::Kafka => JobType::Kafka,
-            },
+            r#type: JobType::new(&job_model.r#type),
             payload: job_model.payload,
+            options: job_model.options.unwrap_or(JsonValue::Null),
+            schedule,
+            occurrences: job_model.occurrences,
+            max_occurrences: job_model.max_occurrences,
+            ends_at: job_model.ends_at.map(|ends_at| ends_at.with_timezone(&Utc)),
+            misfire_policy,
+            expires_at: job_model.expires_at.map(|expires_at| expires_at.with_timezone(&Utc)),
+            calendar: None,
+            calendar_roll,
+            jitter: job_model.jitter_secs.map(Duration::seconds),
+            timeout: job_model.timeout_secs.map(Duration::seconds),
             metadata: match job_metadata_model {
                 None => None,
                 Some(job_metadata) => Some(JobMetadataEntity {
@@ -44,11 +186,71 @@ impl From<(job::Model, Option<Model>)> for JobEntity {
                         JobStatusEnum::Completed => JobMetadataStatus::Completed,
                         JobStatusEnum::Deleted => JobMetadataStatus::Deleted,
                         JobStatusEnum::Failed => JobMetadataStatus::Failed,
+                        JobStatusEnum::Skipped => JobMetadataStatus::Skipped,
+                        JobStatusEnum::Expired => JobMetadataStatus::Expired,
                     },
                     failure: job_metadata.failure,
                     processed_at: job_metadata.processed_at,
                 }),
             },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_fraction_is_deterministic() {
+        let job_id = Uuid::new_v4();
+
+        assert_eq!(seeded_fraction(job_id, 0), seeded_fraction(job_id, 0));
+        assert_ne!(seeded_fraction(job_id, 0), seeded_fraction(job_id, 1));
+    }
+
+    #[test]
+    fn test_seeded_fraction_is_spread_over_unit_interval() {
+        let fractions: Vec<f64> = (0..1000).map(|_| seeded_fraction(Uuid::new_v4(), 0)).collect();
+
+        assert!(fractions.iter().all(|fraction| (0.0..1.0).contains(fraction)));
+        assert!(fractions.iter().any(|fraction| *fraction < 0.1));
+        assert!(fractions.iter().any(|fraction| *fraction > 0.9));
+    }
+
+    #[test]
+    fn test_try_from_rejects_undecodable_columns() {
+        let now = Utc::now();
+        let model = job::Model {
+            id: Uuid::new_v4(),
+            time: now.into(),
+            scheduled_at: now.into(),
+            target: "https://example.com".to_string(),
+            retries: 0,
+            payload: JsonValue::Null,
+            r#type: JobType::HTTP.to_string(),
+            options: None,
+            schedule: None,
+            occurrences: 0,
+            max_occurrences: None,
+            ends_at: None,
+            misfire_policy: None,
+            expires_at: None,
+            calendar: None,
+            calendar_roll: None,
+            jitter_secs: None,
+            timeout_secs: None,
+            created_at: now.naive_utc(),
+            updated_at: now.naive_utc(),
+        };
+
+        assert!(JobEntity::try_from((model.clone(), None)).is_ok());
+        for model in [
+            job::Model { schedule: Some(serde_json::json!({"cron": 5})), ..model.clone() },
+            job::Model { misfire_policy: Some("sometimes".to_string()), ..model.clone() },
+            job::Model { calendar_roll: Some("sideways".to_string()), ..model.clone() },
+        ] {
+            assert!(matches!(JobEntity::try_from((model, None)), Err(DbErr::Custom(_))));
         }
     }
 }
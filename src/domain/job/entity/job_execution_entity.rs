@@ -0,0 +1,232 @@
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::r#enum::job_enum::ExecutionOutcome;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::persistence::job::job_execution;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::Uuid;
+use sea_orm::DbErr;
+use std::collections::BTreeMap;
+
+/// Response headers and bodies are cut to this many bytes before they are stored.
+pub const RESPONSE_HEADERS_LIMIT: usize = 4096;
+pub const RESPONSE_BODY_LIMIT: usize = 4096;
+
+/// One attempt at delivering a job. `attempt` restarts at 1 for every occurrence of a recurring job.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobExecutionEntity {
+    pub id: Uuid,
+    pub job_id: Uuid,
+    pub attempt: i32,
+    pub occurrence: i32,
+    pub executor: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: ExecutionOutcome,
+    pub status_code: Option<i32>,
+    pub kafka_partition: Option<i32>,
+    pub kafka_offset: Option<i64>,
+    pub response_headers: Option<BTreeMap<String, String>>,
+    pub response_body: Option<String>,
+    pub exit_code: Option<i32>,
+    pub stdout: Option<String>,
+    pub stderr: Option<String>,
+    pub rows_affected: Option<i64>,
+    pub error: Option<String>,
+}
+
+impl JobExecutionEntity {
+    pub fn start(job: &JobEntity, executor: &str, started_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            job_id: job.id,
+            attempt: job.retries + 1,
+            occurrence: job.occurrences + 1,
+            executor: executor.to_string(),
+            started_at,
+            finished_at: started_at,
+            outcome: ExecutionOutcome::Success,
+            status_code: None,
+            kafka_partition: None,
+            kafka_offset: None,
+            response_headers: None,
+            response_body: None,
+            exit_code: None,
+            stdout: None,
+            stderr: None,
+            rows_affected: None,
+            error: None,
+        }
+    }
+
+    pub fn finish(self, result: &Result<()>, finished_at: DateTime<Utc>) -> Self {
+        let outcome = match result {
+            Ok(()) => ExecutionOutcome::Success,
+            Err(TempusError::Timeout(_)) => ExecutionOutcome::Timeout,
+            Err(_) => ExecutionOutcome::Failure,
+        };
+
+        Self {
+            finished_at,
+            outcome,
+            error: result.as_ref().err().map(ToString::to_string),
+            ..self
+        }
+    }
+}
+
+/// Keeps headers in name order until `RESPONSE_HEADERS_LIMIT` bytes are used.
+pub fn truncate_headers(headers: BTreeMap<String, String>) -> BTreeMap<String, String> {
+    let mut size = 0;
+
+    headers
+        .into_iter()
+        .take_while(|(name, value)| {
+            size += name.len() + value.len();
+            size <= RESPONSE_HEADERS_LIMIT
+        })
+        .collect()
+}
+
+/// Cuts `body` to `RESPONSE_BODY_LIMIT` bytes without splitting a UTF-8 character.
+pub fn truncate_body(body: &[u8]) -> String {
+    let body = String::from_utf8_lossy(&body[..body.len().min(RESPONSE_BODY_LIMIT)]);
+    let mut end = body.len().min(RESPONSE_BODY_LIMIT);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    body[..end].to_string()
+}
+
+impl TryFrom<job_execution::Model> for JobExecutionEntity {
+    type Error = DbErr;
+
+    fn try_from(model: job_execution::Model) -> std::result::Result<Self, DbErr> {
+        let invalid = |column: &str, e: &dyn std::fmt::Display| {
+            DbErr::Custom(format!("Invalid {} for execution {}: {}", column, model.id, e))
+        };
+
+        let outcome = model.outcome.parse().map_err(|e| invalid("outcome", &e))?;
+        let response_headers = model
+            .response_headers
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| invalid("response_headers", &e))?;
+
+        Ok(JobExecutionEntity {
+            id: model.id,
+            job_id: model.job_id,
+            attempt: model.attempt,
+            occurrence: model.occurrence,
+            executor: model.executor,
+            started_at: model.started_at.with_timezone(&Utc),
+            finished_at: model.finished_at.with_timezone(&Utc),
+            outcome,
+            status_code: model.status_code,
+            kafka_partition: model.kafka_partition,
+            kafka_offset: model.kafka_offset,
+            response_headers,
+            response_body: model.response_body,
+            exit_code: model.exit_code,
+            stdout: model.stdout,
+            stderr: model.stderr,
+            rows_affected: model.rows_affected,
+            error: model.error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finish_records_outcome() {
+        let started_at = DateTime::from_timestamp(0, 0).unwrap();
+        let finished_at = DateTime::from_timestamp(2, 0).unwrap();
+        let execution = |result: Result<()>| {
+            JobExecutionEntity {
+                id: Uuid::nil(),
+                job_id: Uuid::nil(),
+                attempt: 1,
+                occurrence: 1,
+                executor: "http".to_string(),
+                started_at,
+                finished_at: started_at,
+                outcome: ExecutionOutcome::Success,
+                status_code: None,
+                kafka_partition: None,
+                kafka_offset: None,
+                response_headers: None,
+                response_body: None,
+                exit_code: None,
+                stdout: None,
+                stderr: None,
+                rows_affected: None,
+                error: None,
+            }
+            .finish(&result, finished_at)
+        };
+
+        let success = execution(Ok(()));
+        assert_eq!(success.outcome, ExecutionOutcome::Success);
+        assert_eq!(success.finished_at, finished_at);
+        assert_eq!(success.error, None);
+
+        let timeout = execution(Err(TempusError::Timeout(std::time::Duration::from_secs(3))));
+        assert_eq!(timeout.outcome, ExecutionOutcome::Timeout);
+        assert_eq!(timeout.error.as_deref(), Some("Execution timed out after 3s"));
+
+        let failure = execution(Err(TempusError::JobProcessing("boom".to_string())));
+        assert_eq!(failure.outcome, ExecutionOutcome::Failure);
+    }
+
+    #[test]
+    fn test_try_from_rejects_unknown_outcome() {
+        let started_at = DateTime::from_timestamp(0, 0).unwrap();
+        let model = job_execution::Model {
+            id: Uuid::nil(),
+            job_id: Uuid::nil(),
+            attempt: 1,
+            occurrence: 1,
+            executor: "http".to_string(),
+            started_at: started_at.into(),
+            finished_at: started_at.into(),
+            outcome: "success".to_string(),
+            status_code: Some(200),
+            kafka_partition: None,
+            kafka_offset: None,
+            response_headers: Some(serde_json::json!({"content-type": "text/plain"})),
+            response_body: None,
+            error: None,
+            exit_code: None,
+            stdout: None,
+            stderr: None,
+            rows_affected: None,
+        };
+
+        assert_eq!(JobExecutionEntity::try_from(model.clone()).unwrap().outcome, ExecutionOutcome::Success);
+        assert!(JobExecutionEntity::try_from(job_execution::Model { outcome: "skipped".to_string(), ..model }).is_err());
+    }
+
+    #[test]
+    fn test_truncate_body() {
+        assert_eq!(truncate_body(b"ok"), "ok");
+        assert_eq!(truncate_body(&vec![b'a'; RESPONSE_BODY_LIMIT + 10]).len(), RESPONSE_BODY_LIMIT);
+
+        let mut body = vec![b'a'; RESPONSE_BODY_LIMIT - 1];
+        body.extend("é".as_bytes());
+        assert_eq!(truncate_body(&body).len(), RESPONSE_BODY_LIMIT - 1);
+    }
+
+    #[test]
+    fn test_truncate_headers() {
+        let headers = BTreeMap::from([
+            ("a".to_string(), "x".repeat(RESPONSE_HEADERS_LIMIT - 10)),
+            ("b".to_string(), "y".repeat(20)),
+        ]);
+
+        assert_eq!(truncate_headers(headers).keys().collect::<Vec<_>>(), vec!["a"]);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Call options for gRPC jobs, which call `method` on the endpoint named by the job target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobGrpcRequestEntity {
+    /// Full method name, `package.Service/Method`.
+    pub method: String,
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GrpcJobOptions {
+    grpc_method: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+}
+
+impl JobGrpcRequestEntity {
+    /// The job `headers` become call metadata.
+    pub fn from_options(options: &JsonValue, endpoint: &str) -> Result<Self> {
+        let options: GrpcJobOptions = parse_options(options)?;
+        Self::new(endpoint, options.grpc_method.as_deref(), options.headers)
+    }
+
+    pub fn new(endpoint: &str, method: Option<&str>, metadata: BTreeMap<String, String>) -> Result<Self> {
+        if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+            return Err(TempusError::Validation(
+                "gRPC endpoint must start with http:// or https://".to_string(),
+            ));
+        }
+
+        let method = method.ok_or_else(|| TempusError::Validation("grpc_method is required for grpc jobs".to_string()))?;
+        validate_method(method)?;
+
+        // Metadata keys are lowercase on the wire; `grpc-` keys are reserved and binary `-bin` keys
+        // cannot be given as text.
+        let metadata = metadata
+            .into_iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), value))
+            .collect::<BTreeMap<_, _>>();
+
+        if let Some(name) = metadata.keys().find(|name| {
+            name.is_empty()
+                || name.starts_with("grpc-")
+                || name.ends_with("-bin")
+                || !name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        }) {
+            return Err(TempusError::Validation(format!("Invalid metadata key: {:?}", name)));
+        }
+
+        if let Some(name) = metadata
+            .iter()
+            .find_map(|(name, value)| (!value.chars().all(|c| c == ' ' || c.is_ascii_graphic())).then_some(name))
+        {
+            return Err(TempusError::Validation(format!("Invalid value for metadata key: {:?}", name)));
+        }
+
+        Ok(Self {
+            method: method.to_string(),
+            metadata,
+        })
+    }
+
+    /// The fully qualified service name and the method name.
+    pub fn service_and_method(&self) -> (&str, &str) {
+        self.method.split_once('/').unwrap_or((&self.method, ""))
+    }
+}
+
+fn validate_method(method: &str) -> Result<()> {
+    let is_identifier = |name: &str| {
+        name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    let valid = method
+        .split_once('/')
+        .is_some_and(|(service, method)| service.split('.').all(is_identifier) && is_identifier(method));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(TempusError::Validation(format!(
+            "Invalid gRPC method: {:?}. Expected package.Service/Method",
+            method
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENDPOINT: &str = "http://billing:50051";
+
+    #[test]
+    fn test_new_validates_method() {
+        let request = JobGrpcRequestEntity::new(ENDPOINT, Some("billing.v1.Invoices/Issue"), BTreeMap::new()).unwrap();
+
+        assert_eq!(request.service_and_method(), ("billing.v1.Invoices", "Issue"));
+        assert!(JobGrpcRequestEntity::new(ENDPOINT, None, BTreeMap::new()).is_err());
+        assert!(JobGrpcRequestEntity::new(ENDPOINT, Some("Issue"), BTreeMap::new()).is_err());
+        assert!(JobGrpcRequestEntity::new(ENDPOINT, Some("/billing.v1.Invoices/Issue"), BTreeMap::new()).is_err());
+        assert!(JobGrpcRequestEntity::new(ENDPOINT, Some("billing..Invoices/Issue"), BTreeMap::new()).is_err());
+        assert!(JobGrpcRequestEntity::new("billing:50051", Some("billing.Invoices/Issue"), BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_new_validates_metadata() {
+        let metadata = |name: &str, value: &str| BTreeMap::from([(name.to_string(), value.to_string())]);
+        let new = |name: &str, value: &str| JobGrpcRequestEntity::new(ENDPOINT, Some("a.B/C"), metadata(name, value));
+
+        assert_eq!(new("X-Tenant", "acme").unwrap().metadata, metadata("x-tenant", "acme"));
+        assert!(new("grpc-timeout", "1S").is_err());
+        assert!(new("trace-bin", "abc").is_err());
+        assert!(new("x tenant", "acme").is_err());
+        assert!(new("x-tenant", "a\nb").is_err());
+    }
+}
@@ -0,0 +1,364 @@
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::error::{Result, TempusError};
+use reqwest::header::{HeaderName, HeaderValue};
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    Get,
+    #[default]
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+}
+
+impl FromStr for HttpMethod {
+    type Err = TempusError;
+
+    fn from_str(method: &str) -> Result<Self> {
+        match method.to_uppercase().as_str() {
+            "GET" => Ok(HttpMethod::Get),
+            "POST" => Ok(HttpMethod::Post),
+            "PUT" => Ok(HttpMethod::Put),
+            "PATCH" => Ok(HttpMethod::Patch),
+            "DELETE" => Ok(HttpMethod::Delete),
+            "HEAD" => Ok(HttpMethod::Head),
+            "OPTIONS" => Ok(HttpMethod::Options),
+            _ => Err(TempusError::Validation(format!(
+                "Invalid HTTP method: {}. Supported methods: GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS",
+                method
+            ))),
+        }
+    }
+}
+
+/// How the job payload is sent: `form` needs a flat JSON object, `text` a JSON string, and
+/// `none` sends no body at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HttpBodyEncoding {
+    #[default]
+    Json,
+    Form,
+    Text,
+    None,
+}
+
+impl FromStr for HttpBodyEncoding {
+    type Err = TempusError;
+
+    fn from_str(encoding: &str) -> Result<Self> {
+        match encoding {
+            "json" => Ok(HttpBodyEncoding::Json),
+            "form" => Ok(HttpBodyEncoding::Form),
+            "text" => Ok(HttpBodyEncoding::Text),
+            "none" => Ok(HttpBodyEncoding::None),
+            _ => Err(TempusError::Validation(format!(
+                "Invalid body encoding: {}. Supported encodings: json, form, text, none",
+                encoding
+            ))),
+        }
+    }
+}
+
+/// An inclusive range of status codes, written as `204`, `200-299` or `2xx`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct HttpStatusRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl HttpStatusRange {
+    pub fn contains(&self, status: u16) -> bool {
+        (self.start..=self.end).contains(&status)
+    }
+}
+
+impl FromStr for HttpStatusRange {
+    type Err = TempusError;
+
+    fn from_str(range: &str) -> Result<Self> {
+        let invalid = || TempusError::Validation(format!(
+            "Invalid status range: {}. Expected a status code like 204, a range like 200-299 or a class like 2xx",
+            range
+        ));
+        let parse_status = |status: &str| {
+            status
+                .trim()
+                .parse::<u16>()
+                .ok()
+                .filter(|status| (100..=599).contains(status))
+                .ok_or_else(invalid)
+        };
+
+        let (start, end) = match range.trim().to_lowercase().as_str() {
+            class if class.len() == 3 && class.ends_with("xx") => {
+                let start = parse_status(&format!("{}00", &class[..1]))?;
+                (start, start + 99)
+            }
+            range => match range.split_once('-') {
+                Some((start, end)) => (parse_status(start)?, parse_status(end)?),
+                None => (parse_status(range)?, parse_status(range)?),
+            },
+        };
+
+        if start > end {
+            return Err(invalid());
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+impl TryFrom<String> for HttpStatusRange {
+    type Error = TempusError;
+
+    fn try_from(range: String) -> Result<Self> {
+        range.parse()
+    }
+}
+
+impl From<HttpStatusRange> for String {
+    fn from(range: HttpStatusRange) -> Self {
+        if range.start == range.end {
+            range.start.to_string()
+        } else {
+            format!("{}-{}", range.start, range.end)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobHttpRequestEntity {
+    #[serde(default)]
+    pub method: HttpMethod,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub query: BTreeMap<String, String>,
+    #[serde(default)]
+    pub body_encoding: HttpBodyEncoding,
+    /// Responses outside these ranges fail the job; empty means any 2xx status.
+    #[serde(default)]
+    pub success_statuses: Vec<HttpStatusRange>,
+    /// Names the `HTTP_SIGNING_KEYS` entry that signs the request, overriding the destination's
+    /// `HTTP_SIGNING_SECRETS`. Only the name is stored with the job.
+    #[serde(default)]
+    pub signing_key: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HttpJobOptions {
+    method: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    query: BTreeMap<String, String>,
+    body_encoding: Option<String>,
+    #[serde(default)]
+    success_statuses: Vec<String>,
+    signing_key: Option<String>,
+}
+
+impl JobHttpRequestEntity {
+    pub fn from_options(options: &JsonValue, payload: &JsonValue) -> Result<Self> {
+        let options: HttpJobOptions = parse_options(options)?;
+
+        Self::new(
+            options.method.as_deref(),
+            options.headers,
+            options.query,
+            options.body_encoding.as_deref(),
+            &options.success_statuses,
+            options.signing_key,
+            payload,
+        )
+    }
+
+    pub fn new(
+        method: Option<&str>,
+        headers: BTreeMap<String, String>,
+        query: BTreeMap<String, String>,
+        body_encoding: Option<&str>,
+        success_statuses: &[String],
+        signing_key: Option<String>,
+        payload: &JsonValue,
+    ) -> Result<Self> {
+        let method = method.map(HttpMethod::from_str).transpose()?.unwrap_or_default();
+        let body_encoding = body_encoding
+            .map(HttpBodyEncoding::from_str)
+            .transpose()?
+            .unwrap_or_default();
+        let success_statuses = success_statuses
+            .iter()
+            .map(|range| range.parse())
+            .collect::<Result<Vec<HttpStatusRange>>>()?;
+
+        if signing_key.as_deref().is_some_and(str::is_empty) {
+            return Err(TempusError::Validation("Signing key cannot be empty".to_string()));
+        }
+
+        validate_headers(&headers)?;
+        validate_payload(body_encoding, payload)?;
+
+        Ok(Self {
+            method,
+            headers,
+            query,
+            body_encoding,
+            success_statuses,
+            signing_key,
+        })
+    }
+
+    pub fn is_success(&self, status: u16) -> bool {
+        if self.success_statuses.is_empty() {
+            return (200..=299).contains(&status);
+        }
+
+        self.success_statuses.iter().any(|range| range.contains(status))
+    }
+}
+
+fn validate_headers(headers: &BTreeMap<String, String>) -> Result<()> {
+    headers.iter().try_for_each(|(name, value)| {
+        HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| TempusError::Validation(format!("Invalid header name: {}", name)))?;
+        HeaderValue::from_str(value)
+            .map_err(|_| TempusError::Validation(format!("Invalid value for header: {}", name)))?;
+        Ok(())
+    })
+}
+
+fn validate_payload(body_encoding: HttpBodyEncoding, payload: &JsonValue) -> Result<()> {
+    let valid = match body_encoding {
+        HttpBodyEncoding::Json | HttpBodyEncoding::None => true,
+        HttpBodyEncoding::Text => payload.is_string(),
+        HttpBodyEncoding::Form => payload.as_object().is_some_and(|fields| {
+            fields.values().all(|value| !value.is_object() && !value.is_array() && !value.is_null())
+        }),
+    };
+
+    if !valid {
+        return Err(TempusError::Validation(match body_encoding {
+            HttpBodyEncoding::Text => "A text body needs a string payload".to_string(),
+            _ => "A form body needs a payload object with scalar values".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_defaults_to_json_post() {
+        let http_request =
+            JobHttpRequestEntity::new(None, BTreeMap::new(), BTreeMap::new(), None, &[], None, &json!({})).unwrap();
+
+        assert_eq!(http_request, JobHttpRequestEntity::default());
+        assert_eq!(http_request.method, HttpMethod::Post);
+        assert_eq!(http_request.body_encoding, HttpBodyEncoding::Json);
+    }
+
+    #[test]
+    fn test_parses_method_case_insensitively() {
+        assert_eq!("put".parse::<HttpMethod>().unwrap(), HttpMethod::Put);
+        assert_eq!("DELETE".parse::<HttpMethod>().unwrap(), HttpMethod::Delete);
+        assert!("TRACE".parse::<HttpMethod>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_headers() {
+        let headers = |name: &str, value: &str| BTreeMap::from([(name.to_string(), value.to_string())]);
+
+        assert!(JobHttpRequestEntity::new(None, headers("Authorization", "Bearer token"), BTreeMap::new(), None, &[], None, &json!({})).is_ok());
+        assert!(JobHttpRequestEntity::new(None, headers("Bad Header", "value"), BTreeMap::new(), None, &[], None, &json!({})).is_err());
+        assert!(JobHttpRequestEntity::new(None, headers("X-Value", "line\nbreak"), BTreeMap::new(), None, &[], None, &json!({})).is_err());
+    }
+
+    #[test]
+    fn test_validates_payload_for_body_encoding() {
+        let new = |encoding: &str, payload: JsonValue| {
+            JobHttpRequestEntity::new(None, BTreeMap::new(), BTreeMap::new(), Some(encoding), &[], None, &payload)
+        };
+
+        assert!(new("form", json!({"name": "tempus", "count": 1})).is_ok());
+        assert!(new("form", json!({"nested": {"name": "tempus"}})).is_err());
+        assert!(new("form", json!("name=tempus")).is_err());
+        assert!(new("form", json!({"name": null})).is_err());
+        assert!(new("text", json!("hello")).is_ok());
+        assert!(new("text", json!({"message": "hello"})).is_err());
+        assert!(new("none", json!({"ignored": true})).is_ok());
+        assert!(new("xml", json!({})).is_err());
+    }
+
+    #[test]
+    fn test_rejects_empty_signing_key() {
+        let new = |signing_key: &str| {
+            JobHttpRequestEntity::new(None, BTreeMap::new(), BTreeMap::new(), None, &[], Some(signing_key.to_string()), &json!({}))
+        };
+
+        assert!(new("partner").is_ok());
+        assert!(new("").is_err());
+    }
+
+    #[test]
+    fn test_parses_status_ranges() {
+        assert_eq!("204".parse::<HttpStatusRange>().unwrap(), HttpStatusRange { start: 204, end: 204 });
+        assert_eq!("200-302".parse::<HttpStatusRange>().unwrap(), HttpStatusRange { start: 200, end: 302 });
+        assert_eq!("3XX".parse::<HttpStatusRange>().unwrap(), HttpStatusRange { start: 300, end: 399 });
+        assert!("299-200".parse::<HttpStatusRange>().is_err());
+        assert!("9xx".parse::<HttpStatusRange>().is_err());
+        assert!("ok".parse::<HttpStatusRange>().is_err());
+    }
+
+    #[test]
+    fn test_is_success() {
+        let default = JobHttpRequestEntity::default();
+        assert!(default.is_success(200));
+        assert!(default.is_success(204));
+        assert!(!default.is_success(302));
+        assert!(!default.is_success(404));
+
+        let success_statuses = ["2xx".to_string(), "304".to_string()];
+        let custom =
+            JobHttpRequestEntity::new(None, BTreeMap::new(), BTreeMap::new(), None, &success_statuses, None, &json!({})).unwrap();
+        assert!(custom.is_success(201));
+        assert!(custom.is_success(304));
+        assert!(!custom.is_success(302));
+    }
+
+    #[test]
+    fn test_deserializes_stored_request_with_defaults() {
+        let http_request: JobHttpRequestEntity =
+            serde_json::from_value(json!({"method": "PUT", "headers": {"Authorization": "Bearer token"}})).unwrap();
+
+        assert_eq!(http_request.method, HttpMethod::Put);
+        assert_eq!(http_request.headers.get("Authorization").map(String::as_str), Some("Bearer token"));
+        assert_eq!(http_request.body_encoding, HttpBodyEncoding::Json);
+    }
+
+    #[test]
+    fn test_from_options() {
+        let http_request = JobHttpRequestEntity::from_options(&json!({"method": "put", "query": {"page": "1"}}), &json!({})).unwrap();
+
+        assert_eq!(http_request.method, HttpMethod::Put);
+        assert_eq!(http_request.query.get("page").map(String::as_str), Some("1"));
+        assert_eq!(JobHttpRequestEntity::from_options(&JsonValue::Null, &json!({})).unwrap(), JobHttpRequestEntity::default());
+        assert!(JobHttpRequestEntity::from_options(&json!({"key": "order-1"}), &json!({})).is_err());
+        assert!(JobHttpRequestEntity::from_options(&json!({"signing_secrets": ["whsec"]}), &json!({})).is_err());
+    }
+}
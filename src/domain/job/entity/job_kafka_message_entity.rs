@@ -0,0 +1,108 @@
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::domain::job::entity::job_time_entity::JobTimeEntity;
+use crate::error::{Result, TempusError};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Message options for Kafka jobs. Without a `cluster` the default cluster is used, without a
+/// `partition` the producer partitions by `key`, and without a `timestamp` the message is stamped
+/// when it is sent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobKafkaMessageEntity {
+    #[serde(default)]
+    pub cluster: Option<String>,
+    #[serde(default)]
+    pub key: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub partition: Option<i32>,
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KafkaJobOptions {
+    cluster: Option<String>,
+    key: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    partition: Option<i32>,
+    timestamp: Option<JobTimeEntity>,
+}
+
+impl JobKafkaMessageEntity {
+    /// `timestamp` is resolved in `timezone`, like the job time.
+    pub fn from_options(options: &JsonValue, timezone: Option<Tz>) -> Result<Self> {
+        let options: KafkaJobOptions = parse_options(options)?;
+
+        if options.key.as_ref().is_some_and(String::is_empty) {
+            return Err(TempusError::Validation("key cannot be empty".to_string()));
+        }
+
+        Self::new(
+            options.cluster,
+            options.key,
+            options.headers,
+            options.partition,
+            options.timestamp.map(|timestamp| timestamp.resolve(timezone)),
+        )
+    }
+
+    pub fn new(
+        cluster: Option<String>,
+        key: Option<String>,
+        headers: BTreeMap<String, String>,
+        partition: Option<i32>,
+        timestamp: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        if let Some(name) = headers.keys().find(|name| name.is_empty() || name.contains('\0')) {
+            return Err(TempusError::Validation(format!("Invalid header name: {:?}", name)));
+        }
+
+        if partition.is_some_and(|partition| partition < 0) {
+            return Err(TempusError::Validation(
+                "partition cannot be negative".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            cluster,
+            key,
+            headers,
+            partition,
+            timestamp,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_headers_and_partition() {
+        let headers = |name: &str| BTreeMap::from([(name.to_string(), "value".to_string())]);
+
+        assert!(JobKafkaMessageEntity::new(None, Some("order-42".to_string()), headers("trace-id"), Some(3), None).is_ok());
+        assert!(JobKafkaMessageEntity::new(None, None, headers(""), None, None).is_err());
+        assert!(JobKafkaMessageEntity::new(None, None, headers("trace\0id"), None, None).is_err());
+        assert!(JobKafkaMessageEntity::new(None, None, BTreeMap::new(), Some(-1), None).is_err());
+    }
+
+    #[test]
+    fn test_deserializes_stored_message_with_defaults() {
+        let kafka_message: JobKafkaMessageEntity =
+            serde_json::from_value(serde_json::json!({"key": "order-42"})).unwrap();
+
+        assert_eq!(kafka_message.cluster, None);
+        assert_eq!(kafka_message.key.as_deref(), Some("order-42"));
+        assert!(kafka_message.headers.is_empty());
+        assert_eq!(kafka_message.partition, None);
+        assert_eq!(kafka_message.timestamp, None);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Message options for NATS jobs, which publish to the subject named by the job target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobNatsMessageEntity {
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NatsJobOptions {
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+}
+
+impl JobNatsMessageEntity {
+    pub fn from_options(options: &JsonValue, subject: &str) -> Result<Self> {
+        let options: NatsJobOptions = parse_options(options)?;
+        Self::new(subject, options.headers)
+    }
+
+    pub fn new(subject: &str, headers: BTreeMap<String, String>) -> Result<Self> {
+        validate_subject(subject)?;
+
+        // NATS header names are printable ASCII without colons, and values are single-line.
+        if let Some(name) = headers
+            .keys()
+            .find(|name| name.is_empty() || name.contains(|c: char| c == ':' || !c.is_ascii_graphic()))
+        {
+            return Err(TempusError::Validation(format!("Invalid header name: {:?}", name)));
+        }
+
+        if let Some(name) = headers
+            .iter()
+            .find_map(|(name, value)| value.contains(['\r', '\n']).then_some(name))
+        {
+            return Err(TempusError::Validation(format!("Invalid value for header: {:?}", name)));
+        }
+
+        Ok(Self { headers })
+    }
+}
+
+/// Messages are published to a single subject, so wildcards and empty tokens are rejected.
+fn validate_subject(subject: &str) -> Result<()> {
+    let valid = !subject.is_empty()
+        && subject.split('.').all(|token| {
+            !token.is_empty() && token != "*" && token != ">" && !token.contains(char::is_whitespace)
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(TempusError::Validation(format!("Invalid NATS subject: {:?}", subject)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_subject() {
+        assert!(JobNatsMessageEntity::new("orders.created", BTreeMap::new()).is_ok());
+        assert!(JobNatsMessageEntity::new("", BTreeMap::new()).is_err());
+        assert!(JobNatsMessageEntity::new("orders.*", BTreeMap::new()).is_err());
+        assert!(JobNatsMessageEntity::new("orders.>", BTreeMap::new()).is_err());
+        assert!(JobNatsMessageEntity::new("orders..created", BTreeMap::new()).is_err());
+        assert!(JobNatsMessageEntity::new("orders created", BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_new_validates_headers() {
+        let headers = |name: &str, value: &str| BTreeMap::from([(name.to_string(), value.to_string())]);
+
+        assert!(JobNatsMessageEntity::new("orders", headers("Trace-Id", "abc")).is_ok());
+        assert!(JobNatsMessageEntity::new("orders", headers("Trace:Id", "abc")).is_err());
+        assert!(JobNatsMessageEntity::new("orders", headers("Trace Id", "abc")).is_err());
+        assert!(JobNatsMessageEntity::new("orders", headers("Trace-Id", "a\r\nb")).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::job::r#enum::job_enum::RedisCommand;
+use crate::domain::job::entity::job_entity::parse_options;
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::{Deserialize, Serialize};
+
+/// Command options for Redis jobs, which write to the stream, channel or list named by the job
+/// target. Streams are trimmed to about `maxlen` entries when it is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobRedisMessageEntity {
+    pub command: RedisCommand,
+    #[serde(default)]
+    pub maxlen: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RedisJobOptions {
+    redis_command: Option<String>,
+    maxlen: Option<u64>,
+}
+
+impl JobRedisMessageEntity {
+    pub fn from_options(options: &JsonValue) -> Result<Self> {
+        let options: RedisJobOptions = parse_options(options)?;
+        Self::new(options.redis_command.as_deref(), options.maxlen)
+    }
+
+    pub fn new(command: Option<&str>, maxlen: Option<u64>) -> Result<Self> {
+        let command: RedisCommand = command
+            .ok_or_else(|| TempusError::Validation("redis_command is required for redis jobs".to_string()))?
+            .parse()?;
+
+        if maxlen.is_some() && command != RedisCommand::Xadd {
+            return Err(TempusError::Validation(
+                "maxlen is only supported for the xadd redis_command".to_string(),
+            ));
+        }
+
+        if maxlen == Some(0) {
+            return Err(TempusError::Validation("maxlen must be greater than 0".to_string()));
+        }
+
+        Ok(Self { command, maxlen })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates_command_and_maxlen() {
+        assert_eq!(
+            JobRedisMessageEntity::new(Some("xadd"), Some(1000)).unwrap(),
+            JobRedisMessageEntity { command: RedisCommand::Xadd, maxlen: Some(1000) }
+        );
+        assert!(JobRedisMessageEntity::new(Some("publish"), None).is_ok());
+        assert!(JobRedisMessageEntity::new(None, None).is_err());
+        assert!(JobRedisMessageEntity::new(Some("set"), None).is_err());
+        assert!(JobRedisMessageEntity::new(Some("lpush"), Some(1000)).is_err());
+        assert!(JobRedisMessageEntity::new(Some("xadd"), Some(0)).is_err());
+    }
+}
@@ -0,0 +1,324 @@
+use crate::domain::job::entity::job_time_entity::is_repeated_wall_time;
+use crate::error::{Result, TempusError};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use cron::Schedule;
+use rrule::{RRule, RRuleSet, Unvalidated};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Cron and RRULE schedules are evaluated on the wall clock of `timezone` (UTC when unset):
+/// times skipped by a DST gap are skipped and times repeated by a DST overlap fire once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JobScheduleEntity {
+    Cron {
+        expression: String,
+        #[serde(default)]
+        timezone: Option<Tz>,
+    },
+    Interval {
+        seconds: i64,
+        starts_at: DateTime<Utc>,
+    },
+    RRule {
+        rule: String,
+        dtstart: DateTime<Utc>,
+        exdates: Vec<DateTime<Utc>>,
+        #[serde(default)]
+        timezone: Option<Tz>,
+    },
+}
+
+impl JobScheduleEntity {
+    pub fn cron(expression: &str, timezone: Option<Tz>) -> Result<Self> {
+        parse_cron(expression)?;
+        Ok(JobScheduleEntity::Cron {
+            expression: expression.to_string(),
+            timezone,
+        })
+    }
+
+    pub fn interval(seconds: i64, starts_at: DateTime<Utc>) -> Result<Self> {
+        if seconds <= 0 {
+            return Err(TempusError::Validation(
+                "Interval must be greater than 0 seconds".to_string(),
+            ));
+        }
+
+        Ok(JobScheduleEntity::Interval { seconds, starts_at })
+    }
+
+    pub fn rrule(
+        rule: &str,
+        dtstart: DateTime<Utc>,
+        exdates: Vec<DateTime<Utc>>,
+        timezone: Option<Tz>,
+    ) -> Result<Self> {
+        let rule = rule.trim().trim_start_matches("RRULE:").to_string();
+        build_rrule_set(&rule, dtstart, &exdates, timezone)?;
+
+        Ok(JobScheduleEntity::RRule {
+            rule,
+            dtstart,
+            exdates,
+            timezone,
+        })
+    }
+
+    /// Returns the first fire time at or after `from`, or `None` when the schedule has none left.
+    pub fn first_at_or_after(&self, from: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        self.next_after(from - chrono::Duration::nanoseconds(1))
+    }
+
+    /// Returns the first fire time strictly after `after`, or `None` once the schedule is exhausted.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        match self {
+            JobScheduleEntity::Cron {
+                expression,
+                timezone,
+            } => Ok(parse_cron(expression)?
+                .after(&after.with_timezone(&timezone.unwrap_or(Tz::UTC)))
+                .filter(|next| *next > after)
+                .find(|next| !is_repeated_wall_time(next))
+                .map(|next| next.with_timezone(&Utc))),
+            JobScheduleEntity::Interval { seconds, starts_at } => {
+                Ok(next_interval_after(*seconds, *starts_at, after))
+            }
+            JobScheduleEntity::RRule {
+                rule,
+                dtstart,
+                exdates,
+                timezone,
+            } => Ok(next_rrule_after(
+                &build_rrule_set(rule, *dtstart, exdates, *timezone)?,
+                after,
+            )),
+        }
+    }
+}
+
+/// Interval occurrences are anchored to `starts_at`, so late or retried runs never shift the grid.
+fn next_interval_after(seconds: i64, starts_at: DateTime<Utc>, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if after < starts_at {
+        return Some(starts_at);
+    }
+
+    let elapsed = (after - starts_at).num_seconds();
+    let periods = elapsed / seconds + 1;
+
+    starts_at.checked_add_signed(chrono::Duration::seconds(periods.checked_mul(seconds)?))
+}
+
+/// Asks the rule for one occurrence at a time from `after` on, so a long-running schedule is not
+/// replayed from `dtstart`. RRULE times have whole seconds, so stepping one second past a skipped
+/// occurrence never misses the next one.
+fn next_rrule_after(rrule_set: &RRuleSet, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let timezone = rrule_set.get_dt_start().timezone();
+    let mut cursor = after;
+
+    loop {
+        let next = *rrule_set
+            .clone()
+            .after(cursor.with_timezone(&timezone))
+            .all(1)
+            .dates
+            .first()?;
+
+        if next > after && !is_repeated_wall_time(&next) {
+            return Some(next.with_timezone(&Utc));
+        }
+        cursor = next.with_timezone(&Utc) + chrono::Duration::seconds(1);
+    }
+}
+
+fn build_rrule_set(
+    rule: &str,
+    dtstart: DateTime<Utc>,
+    exdates: &[DateTime<Utc>],
+    timezone: Option<Tz>,
+) -> Result<RRuleSet> {
+    let invalid_rrule =
+        |e: rrule::RRuleError| TempusError::Validation(format!("Invalid rrule '{}': {}", rule, e));
+    let timezone = rrule::Tz::Tz(timezone.unwrap_or(Tz::UTC));
+
+    let rrule_set = rule
+        .parse::<RRule<Unvalidated>>()
+        .map_err(invalid_rrule)?
+        .build(dtstart.with_timezone(&timezone))
+        .map_err(invalid_rrule)?;
+
+    Ok(exdates.iter().fold(rrule_set, |rrule_set, exdate| {
+        rrule_set.exdate(exdate.with_timezone(&timezone))
+    }))
+}
+
+fn parse_cron(expression: &str) -> Result<Schedule> {
+    Schedule::from_str(expression).map_err(|e| {
+        TempusError::Validation(format!("Invalid cron expression '{}': {}", expression, e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        on(1, 1, hour, minute, second)
+    }
+
+    fn on(month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2025, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, second)
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn test_cron_rejects_invalid_expression() {
+        assert!(JobScheduleEntity::cron("0 */15 * * * *", None).is_ok());
+        assert!(JobScheduleEntity::cron("not a cron", None).is_err());
+        assert!(JobScheduleEntity::cron("", None).is_err());
+    }
+
+    #[test]
+    fn test_first_at_or_after_includes_from() {
+        let cron = JobScheduleEntity::cron("0 */15 * * * *", None).unwrap();
+        let rrule = JobScheduleEntity::rrule("FREQ=HOURLY", at(9, 0, 0), vec![], None).unwrap();
+        let half_second = chrono::Duration::milliseconds(500);
+
+        assert_eq!(cron.first_at_or_after(at(10, 0, 0)).unwrap(), Some(at(10, 0, 0)));
+        assert_eq!(cron.first_at_or_after(at(10, 0, 0) + half_second).unwrap(), Some(at(10, 15, 0)));
+        assert_eq!(rrule.first_at_or_after(at(10, 0, 0)).unwrap(), Some(at(10, 0, 0)));
+        assert_eq!(rrule.first_at_or_after(at(10, 0, 0) + half_second).unwrap(), Some(at(11, 0, 0)));
+        assert_eq!(rrule.first_at_or_after(at(0, 0, 0)).unwrap(), Some(at(9, 0, 0)));
+    }
+
+    #[test]
+    fn test_cron_next_after() {
+        let schedule = JobScheduleEntity::cron("0 */15 * * * *", None).unwrap();
+
+        assert_eq!(schedule.next_after(at(10, 0, 0)).unwrap(), Some(at(10, 15, 0)));
+        assert_eq!(schedule.next_after(at(10, 7, 30)).unwrap(), Some(at(10, 15, 0)));
+        assert_eq!(schedule.next_after(at(10, 59, 59)).unwrap(), Some(at(11, 0, 0)));
+    }
+
+    #[test]
+    fn test_interval_rejects_non_positive_seconds() {
+        assert!(JobScheduleEntity::interval(90, at(10, 0, 0)).is_ok());
+        assert!(JobScheduleEntity::interval(0, at(10, 0, 0)).is_err());
+        assert!(JobScheduleEntity::interval(-1, at(10, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_interval_next_after_is_anchored_to_start() {
+        let schedule = JobScheduleEntity::interval(90, at(10, 0, 0)).unwrap();
+
+        assert_eq!(schedule.next_after(at(9, 0, 0)).unwrap(), Some(at(10, 0, 0)));
+        assert_eq!(schedule.next_after(at(10, 0, 0)).unwrap(), Some(at(10, 1, 30)));
+        assert_eq!(schedule.next_after(at(10, 1, 45)).unwrap(), Some(at(10, 3, 0)));
+        assert_eq!(schedule.next_after(at(10, 3, 0)).unwrap(), Some(at(10, 4, 30)));
+    }
+
+    #[test]
+    fn test_rrule_rejects_invalid_rule() {
+        assert!(JobScheduleEntity::rrule("FREQ=MONTHLY;BYDAY=-1FR;BYHOUR=9", at(0, 0, 0), vec![], None).is_ok());
+        assert!(JobScheduleEntity::rrule("RRULE:FREQ=DAILY", at(0, 0, 0), vec![], None).is_ok());
+        assert!(JobScheduleEntity::rrule("FREQ=SOMETIMES", at(0, 0, 0), vec![], None).is_err());
+        assert!(JobScheduleEntity::rrule("", at(0, 0, 0), vec![], None).is_err());
+    }
+
+    #[test]
+    fn test_rrule_last_friday_of_the_month() {
+        let schedule =
+            JobScheduleEntity::rrule("FREQ=MONTHLY;BYDAY=-1FR;BYHOUR=9;BYMINUTE=0;BYSECOND=0", at(0, 0, 0), vec![], None)
+                .unwrap();
+        let last_friday_of_january = on(1, 31, 9, 0, 0);
+        let last_friday_of_february = on(2, 28, 9, 0, 0);
+
+        assert_eq!(schedule.next_after(at(0, 0, 0)).unwrap(), Some(last_friday_of_january));
+        assert_eq!(schedule.next_after(last_friday_of_january).unwrap(), Some(last_friday_of_february));
+    }
+
+    #[test]
+    fn test_rrule_honours_exdates() {
+        let schedule = JobScheduleEntity::rrule(
+            "FREQ=DAILY;INTERVAL=1",
+            at(9, 0, 0),
+            vec![at(9, 0, 0) + chrono::Duration::days(1)],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schedule.next_after(at(9, 0, 0)).unwrap(),
+            Some(at(9, 0, 0) + chrono::Duration::days(2))
+        );
+    }
+
+    #[test]
+    fn test_rrule_exhausted_schedule() {
+        let schedule = JobScheduleEntity::rrule("FREQ=DAILY;COUNT=2", at(9, 0, 0), vec![], None).unwrap();
+
+        assert_eq!(
+            schedule.next_after(at(9, 0, 0)).unwrap(),
+            Some(at(9, 0, 0) + chrono::Duration::days(1))
+        );
+        assert_eq!(schedule.next_after(at(9, 0, 0) + chrono::Duration::days(1)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_rrule_next_after_between_occurrences() {
+        let schedule = JobScheduleEntity::rrule("FREQ=DAILY", at(9, 0, 0), vec![], None).unwrap();
+        let late = at(9, 0, 0) + chrono::Duration::days(30) + chrono::Duration::milliseconds(500);
+
+        assert_eq!(schedule.next_after(late).unwrap(), Some(at(9, 0, 0) + chrono::Duration::days(31)));
+    }
+
+    #[test]
+    fn test_cron_in_timezone() {
+        let schedule = JobScheduleEntity::cron("0 0 9 * * *", Some(Tz::Europe__Berlin)).unwrap();
+
+        assert_eq!(schedule.next_after(on(1, 1, 0, 0, 0)).unwrap(), Some(on(1, 1, 8, 0, 0)));
+        assert_eq!(schedule.next_after(on(7, 1, 0, 0, 0)).unwrap(), Some(on(7, 1, 7, 0, 0)));
+    }
+
+    #[test]
+    fn test_cron_skips_dst_gap() {
+        let schedule = JobScheduleEntity::cron("0 30 2 * * *", Some(Tz::America__New_York)).unwrap();
+
+        assert_eq!(schedule.next_after(on(3, 8, 8, 0, 0)).unwrap(), Some(on(3, 10, 6, 30, 0)));
+    }
+
+    #[test]
+    fn test_cron_fires_once_on_dst_overlap() {
+        let schedule = JobScheduleEntity::cron("0 30 1 * * *", Some(Tz::America__New_York)).unwrap();
+
+        assert_eq!(schedule.next_after(on(11, 1, 6, 0, 0)).unwrap(), Some(on(11, 2, 5, 30, 0)));
+        assert_eq!(schedule.next_after(on(11, 2, 5, 30, 0)).unwrap(), Some(on(11, 3, 6, 30, 0)));
+        assert_eq!(schedule.next_after(on(11, 2, 6, 0, 0)).unwrap(), Some(on(11, 3, 6, 30, 0)));
+    }
+
+    #[test]
+    fn test_rrule_fires_once_on_dst_overlap() {
+        let schedule = JobScheduleEntity::rrule(
+            "FREQ=DAILY;BYHOUR=1;BYMINUTE=30;BYSECOND=0",
+            on(11, 1, 5, 30, 0),
+            vec![],
+            Some(Tz::America__New_York),
+        )
+        .unwrap();
+
+        assert_eq!(schedule.next_after(on(11, 1, 5, 30, 0)).unwrap(), Some(on(11, 2, 5, 30, 0)));
+        assert_eq!(schedule.next_after(on(11, 2, 5, 30, 0)).unwrap(), Some(on(11, 3, 6, 30, 0)));
+    }
+
+    #[test]
+    fn test_cron_exhausted_schedule() {
+        let schedule = JobScheduleEntity::cron("0 0 0 1 1 * 2024", None).unwrap();
+
+        assert_eq!(schedule.next_after(at(0, 0, 0)).unwrap(), None);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Parameters of a `sql` job, read from the job payload. The statement itself is registered in
+/// the configuration under the name in the job's target.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobSqlEntity {
+    #[serde(default)]
+    pub params: BTreeMap<String, JsonValue>,
+    /// Fails the execution when the statement affects no rows.
+    #[serde(default)]
+    pub fail_if_no_rows: bool,
+}
+
+impl JobSqlEntity {
+    pub fn from_payload(payload: &JsonValue) -> Result<Self> {
+        match payload {
+            JsonValue::Null => Ok(JobSqlEntity::default()),
+            payload => serde_json::from_value(payload.clone())
+                .map_err(|e| TempusError::Validation(format!("Invalid SQL payload: {}", e))),
+        }
+    }
+
+    /// The values of the statement's `names` parameters in order. Every parameter must be given,
+    /// and nothing else.
+    pub fn bind(&self, names: &[String]) -> Result<Vec<JsonValue>> {
+        if let Some(name) = self.params.keys().find(|name| !names.contains(name)) {
+            return Err(TempusError::Validation(format!("Unknown SQL parameter: {}", name)));
+        }
+
+        names
+            .iter()
+            .map(|name| {
+                self.params
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| TempusError::Validation(format!("Missing SQL parameter: {}", name)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_bind_orders_params_by_statement() {
+        let sql = JobSqlEntity::from_payload(&json!({
+            "params": {"status": "expired", "before": "2026-01-01"},
+            "fail_if_no_rows": true
+        }))
+        .unwrap();
+        let names = vec!["before".to_string(), "status".to_string()];
+
+        assert!(sql.fail_if_no_rows);
+        assert_eq!(sql.bind(&names).unwrap(), vec![json!("2026-01-01"), json!("expired")]);
+        assert!(JobSqlEntity::from_payload(&JsonValue::Null).unwrap().bind(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rejects_invalid_params() {
+        let names = vec!["id".to_string()];
+
+        assert!(JobSqlEntity::from_payload(&json!({"sql": "DELETE FROM job"})).is_err());
+        assert!(JobSqlEntity::from_payload(&json!({"params": [1]})).is_err());
+        assert!(JobSqlEntity::from_payload(&json!({})).unwrap().bind(&names).is_err());
+        assert!(JobSqlEntity::from_payload(&json!({"params": {"id": 1, "other": 2}}))
+            .unwrap()
+            .bind(&names)
+            .is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::error::{Result, TempusError};
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+/// A point in time as sent by clients: either an RFC 3339 timestamp carrying its own offset,
+/// or a wall-clock time that is read in the job timezone (UTC when none is given).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum JobTimeEntity {
+    Offset(DateTime<FixedOffset>),
+    Local(NaiveDateTime),
+}
+
+impl JobTimeEntity {
+    pub fn resolve(&self, timezone: Option<Tz>) -> DateTime<Utc> {
+        match self {
+            JobTimeEntity::Offset(time) => time.with_timezone(&Utc),
+            JobTimeEntity::Local(time) => resolve_local(*time, timezone.unwrap_or(Tz::UTC)),
+        }
+    }
+}
+
+pub fn parse_timezone(name: &str) -> Result<Tz> {
+    name.parse::<Tz>()
+        .map_err(|_| TempusError::Validation(format!("Invalid timezone: {}", name)))
+}
+
+/// Parses an ISO-8601 duration such as `PT5M`, `P1DT12H` or `P2W`. Years and months are not
+/// accepted because their length depends on the date they are applied to.
+pub fn parse_iso8601_duration(duration: &str) -> Result<Duration> {
+    let invalid_duration = || {
+        TempusError::Validation(format!(
+            "Invalid ISO-8601 duration: {}. Use weeks, days, hours, minutes and seconds, e.g. PT5M",
+            duration
+        ))
+    };
+
+    let (date_part, time_part) = match duration.strip_prefix('P').ok_or_else(invalid_duration)?.split_once('T') {
+        Some((_, "")) => return Err(invalid_duration()),
+        Some((date_part, time_part)) => (date_part, time_part),
+        None => (&duration[1..], ""),
+    };
+
+    if date_part.is_empty() && time_part.is_empty() {
+        return Err(invalid_duration());
+    }
+
+    let date_seconds = duration_components(date_part, |unit| match unit {
+        'W' => Some(7 * 24 * 60 * 60),
+        'D' => Some(24 * 60 * 60),
+        _ => None,
+    });
+    let time_seconds = duration_components(time_part, |unit| match unit {
+        'H' => Some(60 * 60),
+        'M' => Some(60),
+        'S' => Some(1),
+        _ => None,
+    });
+
+    date_seconds
+        .zip(time_seconds)
+        .and_then(|(date_seconds, time_seconds)| date_seconds.checked_add(time_seconds))
+        .and_then(Duration::try_seconds)
+        .ok_or_else(invalid_duration)
+}
+
+fn duration_components(part: &str, unit_seconds: impl Fn(char) -> Option<i64>) -> Option<i64> {
+    let mut total: i64 = 0;
+    let mut value = String::new();
+
+    for c in part.chars() {
+        if c.is_ascii_digit() {
+            value.push(c);
+            continue;
+        }
+
+        let seconds = value.parse::<i64>().ok()?.checked_mul(unit_seconds(c)?)?;
+        total = total.checked_add(seconds)?;
+        value.clear();
+    }
+
+    value.is_empty().then_some(total)
+}
+
+/// Maps a wall-clock time to a single instant: the first pass of a repeated (DST overlap) time,
+/// and the shifted instant for a time skipped by a DST gap.
+pub fn resolve_local(time: NaiveDateTime, timezone: Tz) -> DateTime<Utc> {
+    match timezone.from_local_datetime(&time) {
+        LocalResult::Single(resolved) => resolved.with_timezone(&Utc),
+        LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+        LocalResult::None => {
+            let offset_before_gap = timezone
+                .offset_from_utc_datetime(&(time - Duration::days(1)))
+                .fix();
+            Utc.from_utc_datetime(&(time - Duration::seconds(offset_before_gap.local_minus_utc() as i64)))
+        }
+    }
+}
+
+/// True for the second pass of a wall-clock time repeated by a DST overlap.
+pub fn is_repeated_wall_time<Z: TimeZone>(time: &DateTime<Z>) -> bool {
+    match time.timezone().from_local_datetime(&time.naive_local()) {
+        LocalResult::Ambiguous(earliest, _) => earliest != *time,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn local(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn utc(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        local(month, day, hour, minute).and_utc()
+    }
+
+    #[test]
+    fn test_resolve_offset_time() {
+        let time: JobTimeEntity = serde_json::from_str("\"2025-01-01T12:00:00+02:00\"").unwrap();
+
+        assert_eq!(time.resolve(None), utc(1, 1, 10, 0));
+        assert_eq!(time.resolve(Some(Tz::America__New_York)), utc(1, 1, 10, 0));
+    }
+
+    #[test]
+    fn test_resolve_local_time() {
+        let time: JobTimeEntity = serde_json::from_str("\"2025-01-01T12:00:00\"").unwrap();
+
+        assert_eq!(time.resolve(None), utc(1, 1, 12, 0));
+        assert_eq!(time.resolve(Some(Tz::America__New_York)), utc(1, 1, 17, 0));
+    }
+
+    #[test]
+    fn test_resolve_local_time_in_dst_gap_shifts_forward() {
+        assert_eq!(resolve_local(local(3, 9, 2, 30), Tz::America__New_York), utc(3, 9, 7, 30));
+    }
+
+    #[test]
+    fn test_resolve_local_time_in_dst_overlap_uses_first_pass() {
+        assert_eq!(resolve_local(local(11, 2, 1, 30), Tz::America__New_York), utc(11, 2, 5, 30));
+    }
+
+    #[test]
+    fn test_is_repeated_wall_time() {
+        let first_pass = utc(11, 2, 5, 30).with_timezone(&Tz::America__New_York);
+        let second_pass = utc(11, 2, 6, 30).with_timezone(&Tz::America__New_York);
+
+        assert!(!is_repeated_wall_time(&first_pass));
+        assert!(is_repeated_wall_time(&second_pass));
+    }
+
+    #[test]
+    fn test_parse_iso8601_duration() {
+        assert_eq!(parse_iso8601_duration("PT5M").unwrap(), Duration::minutes(5));
+        assert_eq!(parse_iso8601_duration("PT1H30M15S").unwrap(), Duration::seconds(5415));
+        assert_eq!(parse_iso8601_duration("P1DT12H").unwrap(), Duration::hours(36));
+        assert_eq!(parse_iso8601_duration("P2W").unwrap(), Duration::days(14));
+        assert_eq!(parse_iso8601_duration("PT0S").unwrap(), Duration::zero());
+    }
+
+    #[test]
+    fn test_parse_iso8601_duration_rejects_invalid_values() {
+        for duration in ["", "5M", "P", "PT", "P1M", "P1Y", "PT5", "PT-5M", "P1H", "PT1D", "PT1.5S"] {
+            assert!(parse_iso8601_duration(duration).is_err(), "{} should be rejected", duration);
+        }
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(parse_timezone("Europe/Berlin").unwrap(), Tz::Europe__Berlin);
+        assert!(parse_timezone("Mars/Olympus_Mons").is_err());
+    }
+}
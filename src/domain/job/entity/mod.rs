@@ -1,2 +1,15 @@
+pub mod job_amqp_message_entity;
+pub mod job_aws_message_entity;
+pub mod job_command_entity;
+pub mod job_email_entity;
 pub mod job_entity;
+pub mod job_execution_entity;
+pub mod job_grpc_request_entity;
+pub mod job_http_request_entity;
+pub mod job_kafka_message_entity;
 pub mod job_metadata_entity;
+pub mod job_nats_message_entity;
+pub mod job_redis_message_entity;
+pub mod job_schedule_entity;
+pub mod job_sql_entity;
+pub mod job_time_entity;
@@ -1,7 +1,39 @@
-#[derive(Debug, Clone)]
-pub enum JobType {
-    Http,
-    Kafka
+use crate::error::TempusError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Name of the executor a job is delivered with. Names are case-insensitive and stored lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JobType(String);
+
+impl JobType {
+    pub const HTTP: &'static str = "http";
+    pub const KAFKA: &'static str = "kafka";
+    pub const AMQP: &'static str = "amqp";
+    pub const NATS: &'static str = "nats";
+    pub const REDIS: &'static str = "redis";
+    pub const SQS: &'static str = "sqs";
+    pub const SNS: &'static str = "sns";
+    pub const COMMAND: &'static str = "command";
+    pub const EMAIL: &'static str = "email";
+    pub const SQL: &'static str = "sql";
+    pub const GRPC: &'static str = "grpc";
+
+    pub fn new(name: &str) -> Self {
+        JobType(name.trim().to_lowercase())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -11,4 +43,227 @@ pub enum JobMetadataStatus {
     Completed,
     Deleted,
     Failed,
+    Skipped,
+    Expired,
+}
+
+/// What the engine does with a job picked up after its fire time has already passed,
+/// written as `fire_now`, `skip` or `fire_if_within <duration>` (e.g. `fire_if_within 30m`).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum MisfirePolicy {
+    FireNow,
+    Skip,
+    FireIfWithin(Duration),
+}
+
+impl FromStr for MisfirePolicy {
+    type Err = TempusError;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        let invalid_policy = || {
+            TempusError::Validation(format!(
+                "Invalid misfire policy: {}. Supported policies: fire_now, skip, fire_if_within <duration>",
+                policy
+            ))
+        };
+
+        match policy.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["fire_now"] => Ok(MisfirePolicy::FireNow),
+            ["skip"] => Ok(MisfirePolicy::Skip),
+            ["fire_if_within", within] => parse_duration(within)
+                .map(MisfirePolicy::FireIfWithin)
+                .ok_or_else(invalid_policy),
+            _ => Err(invalid_policy()),
+        }
+    }
+}
+
+impl TryFrom<String> for MisfirePolicy {
+    type Error = TempusError;
+
+    fn try_from(policy: String) -> Result<Self, Self::Error> {
+        policy.parse()
+    }
+}
+
+impl fmt::Display for MisfirePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MisfirePolicy::FireNow => write!(f, "fire_now"),
+            MisfirePolicy::Skip => write!(f, "skip"),
+            MisfirePolicy::FireIfWithin(within) => write!(f, "fire_if_within {}s", within.as_secs()),
+        }
+    }
+}
+
+/// Randomisation applied to retry delays so that jobs failing together don't retry together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum RetryJitter {
+    #[default]
+    None,
+    Full,
+    Decorrelated,
+}
+
+impl FromStr for RetryJitter {
+    type Err = TempusError;
+
+    fn from_str(jitter: &str) -> Result<Self, Self::Err> {
+        match jitter {
+            "none" => Ok(RetryJitter::None),
+            "full" => Ok(RetryJitter::Full),
+            "decorrelated" => Ok(RetryJitter::Decorrelated),
+            _ => Err(TempusError::Validation(format!(
+                "Invalid retry jitter: {}. Supported jitters: none, full, decorrelated",
+                jitter
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for RetryJitter {
+    type Error = TempusError;
+
+    fn try_from(jitter: String) -> Result<Self, Self::Error> {
+        jitter.parse()
+    }
+}
+
+/// How a `redis` job writes its payload to the key named by its target.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedisCommand {
+    Xadd,
+    Publish,
+    Lpush,
+    Rpush,
+}
+
+impl FromStr for RedisCommand {
+    type Err = TempusError;
+
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        match command.to_lowercase().as_str() {
+            "xadd" => Ok(RedisCommand::Xadd),
+            "publish" => Ok(RedisCommand::Publish),
+            "lpush" => Ok(RedisCommand::Lpush),
+            "rpush" => Ok(RedisCommand::Rpush),
+            _ => Err(TempusError::Validation(format!(
+                "Invalid redis command: {}. Supported commands: xadd, publish, lpush, rpush",
+                command
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for RedisCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedisCommand::Xadd => write!(f, "XADD"),
+            RedisCommand::Publish => write!(f, "PUBLISH"),
+            RedisCommand::Lpush => write!(f, "LPUSH"),
+            RedisCommand::Rpush => write!(f, "RPUSH"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExecutionOutcome {
+    Success,
+    Failure,
+    Timeout,
+}
+
+impl FromStr for ExecutionOutcome {
+    type Err = TempusError;
+
+    fn from_str(outcome: &str) -> Result<Self, Self::Err> {
+        match outcome {
+            "success" => Ok(ExecutionOutcome::Success),
+            "failure" => Ok(ExecutionOutcome::Failure),
+            "timeout" => Ok(ExecutionOutcome::Timeout),
+            _ => Err(TempusError::Validation(format!("Invalid execution outcome: {}", outcome))),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionOutcome::Success => write!(f, "success"),
+            ExecutionOutcome::Failure => write!(f, "failure"),
+            ExecutionOutcome::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+fn parse_duration(duration: &str) -> Option<Duration> {
+    let unit_start = duration.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = duration.split_at(unit_start);
+    let amount = amount.parse::<u64>().ok()?;
+
+    let seconds = match unit {
+        "s" => Some(amount),
+        "m" => amount.checked_mul(60),
+        "h" => amount.checked_mul(60 * 60),
+        "d" => amount.checked_mul(24 * 60 * 60),
+        _ => None,
+    }?;
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_misfire_policy() {
+        assert_eq!("fire_now".parse::<MisfirePolicy>().unwrap(), MisfirePolicy::FireNow);
+        assert_eq!("skip".parse::<MisfirePolicy>().unwrap(), MisfirePolicy::Skip);
+        assert_eq!(
+            "fire_if_within 30m".parse::<MisfirePolicy>().unwrap(),
+            MisfirePolicy::FireIfWithin(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(
+            "fire_if_within 6h".parse::<MisfirePolicy>().unwrap(),
+            MisfirePolicy::FireIfWithin(Duration::from_secs(6 * 60 * 60))
+        );
+    }
+
+    #[test]
+    fn test_parse_misfire_policy_rejects_invalid_values() {
+        assert!("fire_later".parse::<MisfirePolicy>().is_err());
+        assert!("fire_if_within".parse::<MisfirePolicy>().is_err());
+        assert!("fire_if_within 30".parse::<MisfirePolicy>().is_err());
+        assert!("fire_if_within 30w".parse::<MisfirePolicy>().is_err());
+        assert!("fire_if_within m".parse::<MisfirePolicy>().is_err());
+    }
+
+    #[test]
+    fn test_parse_retry_jitter() {
+        assert_eq!("none".parse::<RetryJitter>().unwrap(), RetryJitter::None);
+        assert_eq!("full".parse::<RetryJitter>().unwrap(), RetryJitter::Full);
+        assert_eq!("decorrelated".parse::<RetryJitter>().unwrap(), RetryJitter::Decorrelated);
+        assert!("equal".parse::<RetryJitter>().is_err());
+    }
+
+    #[test]
+    fn test_parse_redis_command() {
+        assert_eq!("xadd".parse::<RedisCommand>().unwrap(), RedisCommand::Xadd);
+        assert_eq!("PUBLISH".parse::<RedisCommand>().unwrap(), RedisCommand::Publish);
+        assert_eq!("lpush".parse::<RedisCommand>().unwrap(), RedisCommand::Lpush);
+        assert_eq!("rpush".parse::<RedisCommand>().unwrap(), RedisCommand::Rpush);
+        assert!("set".parse::<RedisCommand>().is_err());
+    }
+
+    #[test]
+    fn test_misfire_policy_round_trips_through_display() {
+        let policy = MisfirePolicy::FireIfWithin(Duration::from_secs(90));
+
+        assert_eq!(policy.to_string(), "fire_if_within 90s");
+        assert_eq!(policy.to_string().parse::<MisfirePolicy>().unwrap(), policy);
+    }
 }
@@ -0,0 +1,12 @@
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use sea_orm::prelude::async_trait::async_trait;
+use sea_orm::DbErr;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait JobExecutionRepositoryPort: Send + Sync {
+    async fn save(&self, execution: &JobExecutionEntity) -> Result<(), DbErr>;
+    /// Returns up to `limit` executions of the job in the order they started, resuming after the
+    /// execution with id `after` when given.
+    async fn find_by_job_id(&self, job_id: Uuid, after: Option<Uuid>, limit: u64) -> Result<Vec<JobExecutionEntity>, DbErr>;
+}
@@ -0,0 +1,150 @@
+use crate::domain::job::entity::job_entity::{parse_options, JobEntity};
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::r#enum::job_enum::JobType;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// Delivers jobs of one type. The engine enforces the job timeout and records the returned
+/// execution, so an executor only fills in what it learned about the delivery.
+#[async_trait]
+pub trait JobExecutor: Send + Sync {
+    /// Checks a job before it is created and returns the options to store with it.
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        no_options(request)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>);
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NoOptions {}
+
+/// For executors that take no options: any option is rejected.
+pub fn no_options(request: &CreateJobRequest) -> Result<JsonValue> {
+    parse_options::<NoOptions>(&request.options).map(|_| JsonValue::Null)
+}
+
+/// Executors keyed by the job type name they deliver.
+#[derive(Clone, Default)]
+pub struct JobExecutorRegistry {
+    executors: BTreeMap<JobType, Arc<dyn JobExecutor>>,
+    external: BTreeSet<JobType>,
+}
+
+impl JobExecutorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces any executor already registered under `name`.
+    pub fn register(mut self, name: &str, executor: impl JobExecutor + 'static) -> Self {
+        self.executors.insert(JobType::new(name), Arc::new(executor));
+        self
+    }
+
+    /// Accepts jobs of type `name` without an executor, for types delivered by another process.
+    /// Their options are stored unchecked.
+    pub fn register_external(mut self, name: &str) -> Self {
+        self.external.insert(JobType::new(name));
+        self
+    }
+
+    pub fn get(&self, job_type: &JobType) -> Option<Arc<dyn JobExecutor>> {
+        self.executors.get(job_type).cloned()
+    }
+
+    pub fn contains(&self, job_type: &JobType) -> bool {
+        self.executors.contains_key(job_type) || self.external.contains(job_type)
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self
+            .executors
+            .keys()
+            .chain(&self.external)
+            .map(JobType::as_str)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    pub fn validate(&self, job_type: &JobType, request: &CreateJobRequest) -> Result<JsonValue> {
+        match self.executors.get(job_type) {
+            Some(executor) => executor.validate(request),
+            None if self.external.contains(job_type) => Ok(request.options.clone()),
+            None => Err(TempusError::Validation(format!("Invalid job type: {}", job_type))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopExecutor;
+
+    #[async_trait]
+    impl JobExecutor for NoopExecutor {
+        async fn execute(&self, _job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+            (execution, Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_registry_is_keyed_by_case_insensitive_name() {
+        let registry = JobExecutorRegistry::new()
+            .register("webhook", NoopExecutor)
+            .register("SMS", NoopExecutor);
+
+        assert!(registry.contains(&JobType::new("Webhook")));
+        assert!(registry.get(&JobType::new("sms")).is_some());
+        assert!(registry.get(&JobType::new("email")).is_none());
+        assert_eq!(registry.names(), vec!["sms", "webhook"]);
+    }
+
+    fn request(job_type: &str, options: JsonValue) -> CreateJobRequest {
+        CreateJobRequest {
+            target: "target".to_string(),
+            time: None,
+            delay: None,
+            timezone: None,
+            job_type: job_type.to_string(),
+            payload: JsonValue::Null,
+            options,
+            cron: None,
+            interval: None,
+            ends_at: None,
+            max_occurrences: None,
+            rrule: None,
+            dtstart: None,
+            exdates: Vec::new(),
+            misfire_policy: None,
+            expires_at: None,
+            calendar: None,
+            calendar_roll: None,
+            jitter: None,
+            timeout: None,
+        }
+    }
+
+    #[test]
+    fn test_registry_validates_options() {
+        let registry = JobExecutorRegistry::new()
+            .register("webhook", NoopExecutor)
+            .register_external("sms");
+        let options = serde_json::json!({"sender": "tempus"});
+
+        assert!(registry.validate(&JobType::new("webhook"), &request("webhook", JsonValue::Null)).is_ok());
+        assert!(registry.validate(&JobType::new("webhook"), &request("webhook", options.clone())).is_err());
+        assert_eq!(registry.validate(&JobType::new("sms"), &request("sms", options.clone())).unwrap(), options);
+        assert!(registry.validate(&JobType::new("email"), &request("email", JsonValue::Null)).is_err());
+        assert_eq!(registry.names(), vec!["sms", "webhook"]);
+    }
+}
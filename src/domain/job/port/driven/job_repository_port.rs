@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 use crate::domain::job::entity::job_entity::JobEntity;
 use crate::domain::job::entity::job_metadata_entity::JobMetadataEntity;
 use sea_orm::DbErr;
@@ -8,11 +8,21 @@ use sea_orm::prelude::Uuid;
 #[async_trait]
 pub trait JobRepositoryPort: Send + Sync {
     async fn find_all(&self) -> Result<Vec<JobEntity>, DbErr>;
-    async fn find_and_flag_processing(&self, limit: usize) -> Result<Vec<JobEntity>, DbErr>;
+    /// Flags up to `limit` due jobs as processing, leaving out jobs that already used more than
+    /// `max_retries` retries.
+    async fn find_and_flag_processing(&self, limit: usize, max_retries: i32) -> Result<Vec<JobEntity>, DbErr>;
+    async fn find_by_id(&self, job_id: Uuid) -> Result<Option<JobEntity>, DbErr>;
     async fn increment_retry(&self, job_id: Uuid) -> Result<(), DbErr>;
-    async fn update_time(&self, job_id: Uuid, time: NaiveDateTime) -> Result<(), DbErr>;
-    async fn handle_retry_transaction(&self, job_id: Uuid, new_time: NaiveDateTime, retry_metadata: JobMetadataEntity) -> Result<(), DbErr>;
+    async fn update_time(&self, job_id: Uuid, time: DateTime<Utc>) -> Result<(), DbErr>;
+    async fn handle_retry_transaction(&self, job_id: Uuid, new_time: DateTime<Utc>, retry_metadata: JobMetadataEntity) -> Result<(), DbErr>;
+    async fn handle_reschedule_transaction(&self, job_id: Uuid, new_time: DateTime<Utc>, reschedule_metadata: JobMetadataEntity) -> Result<(), DbErr>;
+    async fn handle_occurrence_transaction(&self, job_id: Uuid, next_time: Option<DateTime<Utc>>, occurrence_metadata: JobMetadataEntity) -> Result<(), DbErr>;
     async fn save(&self, job_entity: &JobEntity) -> Result<(), DbErr>;
     async fn delete_unprocessed(&self, job_id: Uuid) -> Result<bool, DbErr>;
-    async fn update_time_unprocessed(&self, job_id: Uuid, time: NaiveDateTime) -> Result<bool, DbErr>;
+    async fn update_time_unprocessed(&self, job_id: Uuid, time: DateTime<Utc>) -> Result<bool, DbErr>;
+    /// Moves a scheduled job to the time `postpone` picks from its current state, with the job
+    /// locked so concurrent postpones add up. Returns `None` when the job is not scheduled.
+    async fn postpone_unprocessed<F>(&self, job_id: Uuid, postpone: F) -> crate::error::Result<Option<DateTime<Utc>>>
+    where
+        F: FnOnce(&JobEntity) -> crate::error::Result<DateTime<Utc>> + Send;
 }
@@ -1,2 +1,4 @@
 pub mod job_repository_port;
-pub mod job_metadata_repository_port;
\ No newline at end of file
+pub mod job_metadata_repository_port;
+pub mod job_execution_repository_port;
+pub mod job_executor_port;
\ No newline at end of file
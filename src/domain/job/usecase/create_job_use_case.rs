@@ -1,34 +1,86 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use log::info;
 use uuid::Uuid;
 
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use crate::domain::calendar::port::driven::calendar_repository_port::CalendarRepositoryPort;
+use crate::domain::calendar::r#enum::calendar_enum::CalendarRoll;
 use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_schedule_entity::JobScheduleEntity;
+use crate::domain::job::entity::job_time_entity::{parse_iso8601_duration, parse_timezone, JobTimeEntity};
+use crate::domain::job::port::driven::job_executor_port::JobExecutorRegistry;
 use crate::domain::job::port::driven::job_repository_port::JobRepositoryPort;
-use crate::domain::job::r#enum::job_enum::JobType;
+use crate::domain::job::r#enum::job_enum::{JobType, MisfirePolicy};
 use crate::error::{Result, TempusError};
 
-pub struct CreateJobUseCase<R: JobRepositoryPort> {
+pub struct CreateJobUseCase<R: JobRepositoryPort, C: CalendarRepositoryPort> {
     job_repository: R,
+    calendar_repository: C,
+    job_executors: JobExecutorRegistry,
 }
 
-impl<R: JobRepositoryPort> CreateJobUseCase<R> {
-    pub fn new(job_repository: R) -> Self {
-        Self { job_repository }
+impl<R: JobRepositoryPort, C: CalendarRepositoryPort> CreateJobUseCase<R, C> {
+    pub fn new(
+        job_repository: R,
+        calendar_repository: C,
+        job_executors: JobExecutorRegistry,
+    ) -> Self {
+        Self {
+            job_repository,
+            calendar_repository,
+            job_executors,
+        }
     }
 
     pub async fn execute(&self, request: CreateJobRequest) -> Result<CreateJobResponse> {
         let job_type = self.parse_job_type(&request.job_type)?;
+        let timezone = request.timezone.as_deref().map(parse_timezone).transpose()?;
+        let options = self.job_executors.validate(&job_type, &request)?;
+        let time = self.resolve_time(&request, timezone, Utc::now())?;
+        let ends_at = request.ends_at.map(|ends_at| ends_at.resolve(timezone));
+        let expires_at = request.expires_at.map(|expires_at| expires_at.resolve(timezone));
+        let schedule = self.parse_schedule(&request, time, timezone)?;
+        let time = self.first_time(&request, schedule.as_ref(), time)?;
+        self.validate_bounds(&request, schedule.is_some(), time, ends_at)?;
+        self.validate_expiry(time, expires_at, schedule.is_some())?;
+        let misfire_policy = request
+            .misfire_policy
+            .as_deref()
+            .map(str::parse::<MisfirePolicy>)
+            .transpose()?;
+        let (calendar, calendar_roll) = self.find_calendar(&request).await?;
+        let jitter = request.jitter.as_deref().map(parse_iso8601_duration).transpose()?;
+        let timeout = self.parse_timeout(&request)?;
         let job_id = Uuid::new_v4();
 
         let job_entity = JobEntity {
             id: job_id,
-            time: request.time,
+            time,
+            scheduled_at: time,
             target: request.target,
             retries: 0,
             r#type: job_type,
             payload: request.payload,
+            options,
+            schedule,
+            occurrences: 0,
+            max_occurrences: request.max_occurrences,
+            ends_at,
+            misfire_policy,
+            expires_at,
+            calendar,
+            calendar_roll,
+            jitter,
+            timeout,
             metadata: None,
         };
+        let time = job_entity.roll_to_working_day(job_entity.apply_jitter(time, 0));
+        let job_entity = JobEntity {
+            time,
+            scheduled_at: time,
+            ..job_entity
+        };
 
         self.job_repository.save(&job_entity).await
             .map_err(TempusError::from)?;
@@ -41,13 +93,171 @@ impl<R: JobRepositoryPort> CreateJobUseCase<R> {
         })
     }
 
+    /// `delay` is applied to the server clock; without `time` or `delay` the job starts now.
+    fn resolve_time(
+        &self,
+        request: &CreateJobRequest,
+        timezone: Option<Tz>,
+        now: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>> {
+        match (&request.time, &request.delay) {
+            (Some(time), None) => Ok(time.resolve(timezone)),
+            (None, Some(delay)) => Ok(now + parse_iso8601_duration(delay)?),
+            (None, None) => Ok(now),
+            (Some(_), Some(_)) => Err(TempusError::Validation(
+                "Only one of time or delay can be set".to_string(),
+            )),
+        }
+    }
+
+    /// Without `time` or `delay` a recurring job first runs at its first occurrence from now on,
+    /// so an rrule's `dtstart`, BY parts and EXDATEs apply to the first run as well.
+    fn first_time(
+        &self,
+        request: &CreateJobRequest,
+        schedule: Option<&JobScheduleEntity>,
+        time: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>> {
+        match schedule {
+            Some(schedule) if request.time.is_none() && request.delay.is_none() => schedule
+                .first_at_or_after(time)?
+                .ok_or_else(|| TempusError::Validation("The schedule has no occurrence left".to_string())),
+            _ => Ok(time),
+        }
+    }
+
+    fn parse_schedule(
+        &self,
+        request: &CreateJobRequest,
+        time: DateTime<Utc>,
+        timezone: Option<Tz>,
+    ) -> Result<Option<JobScheduleEntity>> {
+        let schedule = match (&request.cron, request.interval, &request.rrule) {
+            (Some(expression), None, None) => Some(JobScheduleEntity::cron(expression, timezone)?),
+            (None, Some(seconds), None) => Some(JobScheduleEntity::interval(seconds, time)?),
+            (None, None, Some(rule)) => Some(JobScheduleEntity::rrule(
+                rule,
+                request
+                    .dtstart
+                    .map(|dtstart| dtstart.resolve(timezone))
+                    .unwrap_or(time),
+                request
+                    .exdates
+                    .iter()
+                    .map(|exdate| exdate.resolve(timezone))
+                    .collect(),
+                timezone,
+            )?),
+            (None, None, None) => None,
+            _ => {
+                return Err(TempusError::Validation(
+                    "Only one of cron, interval or rrule can be set".to_string(),
+                ));
+            }
+        };
+
+        if request.rrule.is_none() && (request.dtstart.is_some() || !request.exdates.is_empty()) {
+            return Err(TempusError::Validation(
+                "dtstart and exdates require an rrule schedule".to_string(),
+            ));
+        }
+
+        Ok(schedule)
+    }
+
+    fn validate_bounds(
+        &self,
+        request: &CreateJobRequest,
+        has_schedule: bool,
+        time: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        if !has_schedule && (ends_at.is_some() || request.max_occurrences.is_some()) {
+            return Err(TempusError::Validation(
+                "ends_at and max_occurrences require a cron, interval or rrule schedule".to_string(),
+            ));
+        }
+
+        if ends_at.is_some_and(|ends_at| ends_at < time) {
+            return Err(TempusError::Validation(
+                "ends_at cannot be before time".to_string(),
+            ));
+        }
+
+        if request.max_occurrences.is_some_and(|max_occurrences| max_occurrences < 1) {
+            return Err(TempusError::Validation(
+                "max_occurrences must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// The deadline is for a single delivery; recurring jobs stop at `ends_at` instead.
+    fn validate_expiry(&self, time: DateTime<Utc>, expires_at: Option<DateTime<Utc>>, has_schedule: bool) -> Result<()> {
+        if expires_at.is_some() && has_schedule {
+            return Err(TempusError::Validation(
+                "expires_at cannot be used with cron, interval or rrule; use ends_at".to_string(),
+            ));
+        }
+
+        if expires_at.is_some_and(|expires_at| expires_at < time) {
+            return Err(TempusError::Validation(
+                "expires_at cannot be before time".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn parse_timeout(&self, request: &CreateJobRequest) -> Result<Option<chrono::Duration>> {
+        let timeout = request.timeout.as_deref().map(parse_iso8601_duration).transpose()?;
+
+        if timeout.is_some_and(|timeout| timeout <= chrono::Duration::zero()) {
+            return Err(TempusError::Validation(
+                "timeout must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(timeout)
+    }
+
+    async fn find_calendar(
+        &self,
+        request: &CreateJobRequest,
+    ) -> Result<(Option<CalendarEntity>, CalendarRoll)> {
+        let calendar_roll = request
+            .calendar_roll
+            .as_deref()
+            .map(str::parse::<CalendarRoll>)
+            .transpose()?;
+
+        let Some(name) = &request.calendar else {
+            if calendar_roll.is_some() {
+                return Err(TempusError::Validation(
+                    "calendar_roll requires a calendar".to_string(),
+                ));
+            }
+            return Ok((None, CalendarRoll::default()));
+        };
+
+        let calendar = self.calendar_repository.find_by_name(name).await
+            .map_err(TempusError::from)?
+            .ok_or_else(|| TempusError::Validation(format!("Calendar not found: {}", name)))?;
+
+        Ok((Some(calendar), calendar_roll.unwrap_or_default()))
+    }
+
     fn parse_job_type(&self, job_type_str: &str) -> Result<JobType> {
-        match job_type_str.to_lowercase().as_str() {
-            "kafka" => Ok(JobType::Kafka),
-            "http" => Ok(JobType::Http),
-            _ => Err(TempusError::Validation(format!(
-                "Invalid job type: {}. Supported types: http", 
-                job_type_str
+        let job_type = JobType::new(job_type_str);
+
+        if self.job_executors.contains(&job_type) {
+            Ok(job_type)
+        } else {
+            Err(TempusError::Validation(format!(
+                "Invalid job type: {}. Supported types: {}",
+                job_type_str,
+                self.job_executors.names().join(", ")
             )))
         }
     }
@@ -56,13 +266,146 @@ impl<R: JobRepositoryPort> CreateJobUseCase<R> {
 #[derive(Debug)]
 pub struct CreateJobRequest {
     pub target: String,
-    pub time: NaiveDateTime,
+    pub time: Option<JobTimeEntity>,
+    pub delay: Option<String>,
+    pub timezone: Option<String>,
     pub job_type: String,
     pub payload: sea_orm::JsonValue,
+    /// Executor options, e.g. the HTTP method or the Kafka key; each executor checks its own.
+    pub options: sea_orm::JsonValue,
+    pub cron: Option<String>,
+    pub interval: Option<i64>,
+    pub ends_at: Option<JobTimeEntity>,
+    pub max_occurrences: Option<i32>,
+    pub rrule: Option<String>,
+    pub dtstart: Option<JobTimeEntity>,
+    pub exdates: Vec<JobTimeEntity>,
+    pub misfire_policy: Option<String>,
+    pub expires_at: Option<JobTimeEntity>,
+    pub calendar: Option<String>,
+    pub calendar_roll: Option<String>,
+    pub jitter: Option<String>,
+    pub timeout: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct CreateJobResponse {
     pub id: Uuid,
     pub message: String,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::calendar::r#enum::calendar_enum::CalendarDeletion;
+    use crate::infrastructure::persistence::job::in_memory_job_repository::InMemoryJobRepository;
+    use chrono::{Datelike, NaiveDate, Timelike, Weekday};
+    use sea_orm::prelude::async_trait::async_trait;
+    use sea_orm::DbErr;
+
+    struct NoCalendars;
+
+    #[async_trait]
+    impl CalendarRepositoryPort for NoCalendars {
+        async fn find_all(&self) -> std::result::Result<Vec<CalendarEntity>, DbErr> {
+            Ok(vec![])
+        }
+
+        async fn find_by_name(&self, _name: &str) -> std::result::Result<Option<CalendarEntity>, DbErr> {
+            Ok(None)
+        }
+
+        async fn save(&self, _calendar: &CalendarEntity) -> std::result::Result<(), DbErr> {
+            Ok(())
+        }
+
+        async fn delete_unused(&self, _name: &str) -> std::result::Result<CalendarDeletion, DbErr> {
+            Ok(CalendarDeletion::NotFound)
+        }
+    }
+
+    fn request() -> CreateJobRequest {
+        CreateJobRequest {
+            target: "https://example.com".to_string(),
+            time: None,
+            delay: None,
+            timezone: None,
+            job_type: JobType::HTTP.to_string(),
+            payload: sea_orm::JsonValue::Null,
+            options: sea_orm::JsonValue::Null,
+            cron: None,
+            interval: None,
+            ends_at: None,
+            max_occurrences: None,
+            rrule: None,
+            dtstart: None,
+            exdates: vec![],
+            misfire_policy: None,
+            expires_at: None,
+            calendar: None,
+            calendar_roll: None,
+            jitter: None,
+            timeout: None,
+        }
+    }
+
+    fn local(year: i32, month: u32, day: u32, hour: u32) -> JobTimeEntity {
+        JobTimeEntity::Local(NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, 0, 0).unwrap())
+    }
+
+    async fn create(request: CreateJobRequest) -> Result<JobEntity> {
+        let job_repository = InMemoryJobRepository::new();
+        let use_case = CreateJobUseCase::new(
+            job_repository.clone(),
+            NoCalendars,
+            JobExecutorRegistry::new().register_external(JobType::HTTP),
+        );
+
+        let response = use_case.execute(request).await?;
+        Ok(job_repository.job(response.id).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_recurring_job_without_time_starts_at_first_occurrence() {
+        let job = create(CreateJobRequest {
+            rrule: Some("FREQ=MONTHLY;BYDAY=-1FR;BYHOUR=9;BYMINUTE=0;BYSECOND=0".to_string()),
+            dtstart: Some(local(2100, 1, 1, 9)),
+            exdates: vec![local(2100, 1, 29, 9)],
+            ..request()
+        })
+        .await
+        .unwrap();
+        assert_eq!(job.time, local(2100, 2, 26, 9).resolve(None));
+        assert_eq!(job.scheduled_at, job.time);
+
+        let now = Utc::now();
+        let job = create(CreateJobRequest {
+            cron: Some("0 0 9 * * Fri".to_string()),
+            ..request()
+        })
+        .await
+        .unwrap();
+        assert!(job.time >= now && job.time <= now + chrono::Duration::days(7));
+        assert_eq!((job.time.weekday(), job.time.hour(), job.time.minute()), (Weekday::Fri, 9, 0));
+
+        let job = create(CreateJobRequest {
+            cron: Some("0 0 9 * * Fri".to_string()),
+            time: Some(local(2100, 1, 5, 12)),
+            ..request()
+        })
+        .await
+        .unwrap();
+        assert_eq!(job.time, local(2100, 1, 5, 12).resolve(None));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_schedule_is_rejected() {
+        let result = create(CreateJobRequest {
+            rrule: Some("FREQ=DAILY;COUNT=2".to_string()),
+            dtstart: Some(local(2000, 1, 1, 9)),
+            ..request()
+        })
+        .await;
+
+        assert!(matches!(result, Err(TempusError::Validation(_))));
+    }
+}
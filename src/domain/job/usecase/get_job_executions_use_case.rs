@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::port::driven::job_execution_repository_port::JobExecutionRepositoryPort;
+use crate::domain::job::port::driven::job_repository_port::JobRepositoryPort;
+use crate::error::{Result, TempusError};
+
+pub struct GetJobExecutionsUseCase<R: JobRepositoryPort, E: JobExecutionRepositoryPort> {
+    job_repository: R,
+    job_execution_repository: E,
+}
+
+impl<R: JobRepositoryPort, E: JobExecutionRepositoryPort> GetJobExecutionsUseCase<R, E> {
+    pub fn new(job_repository: R, job_execution_repository: E) -> Self {
+        Self {
+            job_repository,
+            job_execution_repository,
+        }
+    }
+
+    pub async fn execute(&self, job_id: Uuid, after: Option<Uuid>, limit: u64) -> Result<Vec<JobExecutionEntity>> {
+        self.job_repository.find_by_id(job_id).await
+            .map_err(TempusError::from)?
+            .ok_or_else(|| TempusError::Validation(format!("Job not found: {}", job_id)))?;
+
+        self.job_execution_repository.find_by_job_id(job_id, after, limit).await
+            .map_err(TempusError::from)
+    }
+}
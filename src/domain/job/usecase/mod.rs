@@ -1,6 +1,7 @@
 pub mod process_job_use_case;
 pub mod create_job_use_case;
 pub mod delete_job_use_case;
+pub mod get_job_executions_use_case;
 pub mod update_job_time_use_case;
 
 pub use create_job_use_case::{CreateJobUseCase, CreateJobRequest as DomainCreateJobRequest, CreateJobResponse as DomainCreateJobResponse};
@@ -1,50 +1,51 @@
-use crate::config::app_config::AppConfig;
-use crate::domain::job::entity::job_entity::JobEntity;
+use crate::config::app_config::{AppConfig, EngineConfig};
+use crate::domain::job::entity::job_entity::{seeded_fraction, JobEntity};
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
 use crate::domain::job::entity::job_metadata_entity::JobMetadataEntity;
-use crate::domain::job::r#enum::job_enum::{JobMetadataStatus, JobType};
+use crate::domain::job::r#enum::job_enum::{JobMetadataStatus, MisfirePolicy, RetryJitter};
+use crate::domain::job::port::driven::job_execution_repository_port::JobExecutionRepositoryPort;
+use crate::domain::job::port::driven::job_executor_port::JobExecutorRegistry;
 use crate::domain::job::port::driven::job_metadata_repository_port::JobMetadataRepositoryPort;
 use crate::domain::job::port::driven::job_repository_port::JobRepositoryPort;
 use crate::domain::job::port::driver::process_job_use_case_port::ProcessJobUseCasePort;
 use crate::error::{Result, TempusError};
-use crate::infrastructure::kafka::kafka_publisher::publish_kafka_message;
-use crate::infrastructure::metrics::{increment_jobs_processed, observe_job_duration, increment_http_requests, increment_kafka_messages, increment_current_processing_jobs, decrement_current_processing_jobs};
-use chrono::{NaiveDateTime, Utc};
+use crate::infrastructure::metrics::{increment_jobs_processed, observe_job_duration, increment_current_processing_jobs, decrement_current_processing_jobs};
+use chrono::{DateTime, Utc};
 use std::time::Instant;
 use log::{error, info, warn};
-use once_cell::sync::Lazy;
-use reqwest::{Client, Response};
-use sea_orm::JsonValue;
+use sea_orm::prelude::Uuid;
 use std::sync::Arc;
 
 pub struct ProcessJobUseCase<
     JR: JobRepositoryPort + Send + Sync,
     JMR: JobMetadataRepositoryPort + Send + Sync,
+    JER: JobExecutionRepositoryPort + Send + Sync,
 > {
     job_repository: JR,
     job_metadata_repository: JMR,
+    job_execution_repository: JER,
+    job_executors: JobExecutorRegistry,
     config: AppConfig,
 }
 
-fn create_http_client(config: &AppConfig) -> Result<Client> {
-    Client::builder()
-        .pool_idle_timeout(config.http.pool_idle_timeout())
-        .timeout(config.http.request_timeout())
-        .build()
-        .map_err(TempusError::Http)
-}
-
-static HTTP_CLIENT: Lazy<Result<Client>> = Lazy::new(|| {
-    AppConfig::load()
-        .and_then(|config| create_http_client(&config))
-});
-
-impl<JR: JobRepositoryPort + Send + Sync, JMR: JobMetadataRepositoryPort + Send + Sync>
-    ProcessJobUseCase<JR, JMR>
+impl<
+    JR: JobRepositoryPort + Send + Sync,
+    JMR: JobMetadataRepositoryPort + Send + Sync,
+    JER: JobExecutionRepositoryPort + Send + Sync,
+> ProcessJobUseCase<JR, JMR, JER>
 {
-    pub fn new(job_repository: JR, job_metadata_repository: JMR, config: &AppConfig) -> Self {
+    pub fn new(
+        job_repository: JR,
+        job_metadata_repository: JMR,
+        job_execution_repository: JER,
+        job_executors: JobExecutorRegistry,
+        config: &AppConfig,
+    ) -> Self {
         Self {
             job_repository,
             job_metadata_repository,
+            job_execution_repository,
+            job_executors,
             config: config.clone(),
         }
     }
@@ -59,10 +60,218 @@ fn create_success_metadata(metadata: JobMetadataEntity) -> JobMetadataEntity {
     }
 }
 
-async fn handle_success<JMR>(metadata: JobMetadataEntity, job_metadata_repository: JMR) -> Result<()>
+/// A schedule that ends on a failed occurrence leaves the job `Failed`, not `Completed`.
+fn create_occurrence_metadata(
+    job_metadata: &JobMetadataEntity,
+    next_time: Option<DateTime<Utc>>,
+    failure: Option<String>,
+) -> JobMetadataEntity {
+    JobMetadataEntity {
+        job_id: job_metadata.job_id,
+        status: match (next_time, &failure) {
+            (Some(_), _) => JobMetadataStatus::Scheduled,
+            (None, Some(_)) => JobMetadataStatus::Failed,
+            (None, None) => JobMetadataStatus::Completed,
+        },
+        failure,
+        processed_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+const MAX_ROLLED_OCCURRENCES: usize = 10_000;
+
+// Seeded fractions for occurrences use the occurrence number as salt; these keep retry and
+// spreading offsets independent from them.
+const RETRY_SALT: u64 = 1 << 32;
+const SPREAD_SALT: u64 = 1 << 33;
+
+fn occurrences_exhausted(job: &JobEntity) -> bool {
+    job.max_occurrences
+        .is_some_and(|max_occurrences| job.occurrences + 1 >= max_occurrences)
+}
+
+fn next_occurrence(job: &JobEntity) -> Result<Option<DateTime<Utc>>> {
+    next_occurrence_after(job, job.scheduled_at)
+}
+
+fn next_occurrence_after(job: &JobEntity, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+    let Some(schedule) = &job.schedule else {
+        return Ok(None);
+    };
+
+    if occurrences_exhausted(job) {
+        return Ok(None);
+    }
+
+    // Rolling backwards can land on or before `after`; those occurrences are merged into the
+    // one that already fired.
+    let mut schedule_after = after;
+    for _ in 0..MAX_ROLLED_OCCURRENCES {
+        let Some(next_time) = schedule.next_after(schedule_after)? else {
+            return Ok(None);
+        };
+
+        let rolled_time = job.roll_to_working_day(job.apply_jitter(next_time, job.occurrences + 1));
+        if rolled_time > after {
+            return Ok(Some(rolled_time)
+                .filter(|next_time| job.ends_at.is_none_or(|ends_at| *next_time <= ends_at)));
+        }
+
+        schedule_after = next_time;
+    }
+
+    Ok(None)
+}
+
+async fn handle_occurrence<JR>(
+    job: &JobEntity,
+    job_metadata: &JobMetadataEntity,
+    job_repository: JR,
+    failure: Option<String>,
+) -> Result<()>
+where
+    JR: JobRepositoryPort + Send + Sync,
+{
+    let next_time = next_occurrence(job)?;
+    let occurrence_metadata = create_occurrence_metadata(job_metadata, next_time, failure);
+
+    match next_time {
+        Some(next_time) => info!("Job {} scheduled for its next occurrence at {}", job.id, next_time),
+        None => info!("Job {} reached the end of its schedule after {} occurrences", job.id, job.occurrences + 1),
+    }
+
+    job_repository
+        .handle_occurrence_transaction(job.id, next_time, occurrence_metadata)
+        .await
+        .map_err(TempusError::Database)
+}
+
+fn is_misfired(job: &JobEntity, now: DateTime<Utc>, engine: &EngineConfig) -> bool {
+    let Ok(lateness) = (now - job.time).to_std() else {
+        return false;
+    };
+
+    match job.misfire_policy.unwrap_or(engine.misfire_policy) {
+        MisfirePolicy::FireNow => false,
+        MisfirePolicy::Skip => lateness > engine.misfire_threshold(),
+        MisfirePolicy::FireIfWithin(within) => lateness > within,
+    }
+}
+
+fn create_skipped_metadata(
+    job_metadata: &JobMetadataEntity,
+    next_time: Option<DateTime<Utc>>,
+) -> JobMetadataEntity {
+    JobMetadataEntity {
+        job_id: job_metadata.job_id,
+        status: match next_time {
+            Some(_) => JobMetadataStatus::Scheduled,
+            None => JobMetadataStatus::Skipped,
+        },
+        failure: None,
+        processed_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+/// Recurring jobs skip every missed occurrence and move on to the first one after `now`.
+async fn handle_misfire<JR, JMR>(
+    job: &JobEntity,
+    job_metadata: &JobMetadataEntity,
+    job_repository: JR,
+    job_metadata_repository: JMR,
+    now: DateTime<Utc>,
+) -> Result<()>
+where
+    JR: JobRepositoryPort + Send + Sync,
+    JMR: JobMetadataRepositoryPort + Send + Sync,
+{
+    warn!("Job {} misfired: it was due at {}, skipping it", job.id, job.time);
+
+    if job.schedule.is_some() {
+        let next_time = next_occurrence_after(job, now)?;
+        let skipped_metadata = create_skipped_metadata(job_metadata, next_time);
+
+        return job_repository
+            .handle_occurrence_transaction(job.id, next_time, skipped_metadata)
+            .await
+            .map_err(TempusError::Database)
+            .map(|_| increment_jobs_processed("skipped"));
+    }
+
+    job_metadata_repository
+        .update_status(create_skipped_metadata(job_metadata, None))
+        .await
+        .map_err(TempusError::Database)
+        .map(|_| increment_jobs_processed("skipped"))
+}
+
+/// Jobs are spread when they are overdue by more than the spread window, or when they were picked
+/// up in a batch larger than the threshold. A job that was already moved off its scheduled time,
+/// by an earlier spread or a retry, is not spread again by a later burst.
+fn should_spread(job: &JobEntity, batch_size: usize, now: DateTime<Utc>, engine: &EngineConfig) -> bool {
+    if engine.spread_overdue_secs == 0 {
+        return false;
+    }
+
+    let overdue = (now - job.time).to_std().is_ok_and(|lateness| lateness > engine.spread_overdue());
+    let burst = engine.spread_batch_threshold > 0
+        && batch_size > engine.spread_batch_threshold
+        && job.time == job.scheduled_at;
+
+    overdue || burst
+}
+
+/// Overdue jobs are moved to a seeded offset within the spread window, never past their deadline.
+fn spread_time(job: &JobEntity, now: DateTime<Utc>, engine: &EngineConfig) -> DateTime<Utc> {
+    let window_millis = engine.spread_overdue().as_millis() as f64;
+    let offset = chrono::Duration::milliseconds((window_millis * seeded_fraction(job.id, SPREAD_SALT)) as i64);
+
+    job.expires_at
+        .map_or(now + offset, |expires_at| (now + offset).min(expires_at))
+}
+
+fn create_spread_metadata(job_metadata: &JobMetadataEntity) -> JobMetadataEntity {
+    JobMetadataEntity {
+        job_id: job_metadata.job_id,
+        status: JobMetadataStatus::Scheduled,
+        failure: job_metadata.failure.clone(),
+        processed_at: job_metadata.processed_at,
+    }
+}
+
+async fn handle_spread<JR>(
+    job: &JobEntity,
+    job_metadata: &JobMetadataEntity,
+    job_repository: JR,
+    new_time: DateTime<Utc>,
+) -> Result<()>
 where
+    JR: JobRepositoryPort + Send + Sync,
+{
+    info!("Job {} was due at {}, spreading it to {}", job.id, job.time, new_time);
+
+    job_repository
+        .handle_reschedule_transaction(job.id, new_time, create_spread_metadata(job_metadata))
+        .await
+        .map_err(TempusError::Database)
+}
+
+async fn handle_success<JR, JMR>(
+    job: &JobEntity,
+    metadata: JobMetadataEntity,
+    job_repository: JR,
+    job_metadata_repository: JMR,
+) -> Result<()>
+where
+    JR: JobRepositoryPort + Send + Sync,
     JMR: JobMetadataRepositoryPort + Send + Sync + 'static,
 {
+    if job.schedule.is_some() {
+        return handle_occurrence(job, &metadata, job_repository, None)
+            .await
+            .map(|_| increment_jobs_processed("success"));
+    }
+
     let updated_metadata = create_success_metadata(metadata);
 
     job_metadata_repository
@@ -80,9 +289,87 @@ fn calculate_delay_minutes(retries: i32, base_delay_minutes: u32) -> u32 {
     base_delay_minutes * (2u32.pow(retries as u32))
 }
 
-fn backoff(time: NaiveDateTime, retries: i32, base_delay_minutes: u32) -> NaiveDateTime {
-    let delay_minutes = calculate_delay_minutes(retries, base_delay_minutes);
-    time + chrono::Duration::minutes(delay_minutes as i64)
+/// Full jitter picks a delay between zero and the exponential delay; decorrelated jitter picks one
+/// between the base delay and three times the previous one, capped at the exponential delay.
+fn calculate_delay_seconds(job_id: Uuid, retries: i32, base_delay_minutes: u32, jitter: RetryJitter) -> i64 {
+    let base_delay_seconds = base_delay_minutes as f64 * 60.0;
+    let exponential_delay_seconds = calculate_delay_minutes(retries, base_delay_minutes) as f64 * 60.0;
+
+    let delay_seconds = match jitter {
+        RetryJitter::None => exponential_delay_seconds,
+        RetryJitter::Full => exponential_delay_seconds * seeded_fraction(job_id, RETRY_SALT + retries as u64),
+        RetryJitter::Decorrelated => (1..=retries).fold(base_delay_seconds, |previous_delay_seconds, retry| {
+            let fraction = seeded_fraction(job_id, RETRY_SALT + retry as u64);
+            let delay_seconds = base_delay_seconds + (previous_delay_seconds * 3.0 - base_delay_seconds) * fraction;
+            delay_seconds.min(exponential_delay_seconds)
+        }),
+    };
+
+    delay_seconds as i64
+}
+
+fn backoff(
+    time: DateTime<Utc>,
+    job_id: Uuid,
+    retries: i32,
+    base_delay_minutes: u32,
+    jitter: RetryJitter,
+) -> DateTime<Utc> {
+    let delay_seconds = calculate_delay_seconds(job_id, retries, base_delay_minutes, jitter);
+    time + chrono::Duration::seconds(delay_seconds)
+}
+
+/// A `retry_after` time requested by the target replaces the computed backoff. Returns `None`
+/// when the retry would land past the job's `expires_at` deadline.
+fn next_retry_time(
+    job: &JobEntity,
+    engine: &EngineConfig,
+    retry_after: Option<DateTime<Utc>>,
+) -> Option<DateTime<Utc>> {
+    let retry_time = job.roll_to_working_day(retry_after.unwrap_or_else(|| {
+        backoff(
+            job.time,
+            job.id,
+            job.retries + 1,
+            engine.base_delay_minutes,
+            engine.retry_jitter,
+        )
+    }));
+
+    job.expires_at
+        .is_none_or(|expires_at| retry_time <= expires_at)
+        .then_some(retry_time)
+}
+
+fn is_expired(job: &JobEntity, now: DateTime<Utc>) -> bool {
+    job.expires_at.is_some_and(|expires_at| now > expires_at)
+}
+
+fn create_expired_metadata(job_metadata: &JobMetadataEntity, failure: Option<String>) -> JobMetadataEntity {
+    JobMetadataEntity {
+        job_id: job_metadata.job_id,
+        status: JobMetadataStatus::Expired,
+        failure,
+        processed_at: Some(Utc::now().naive_utc()),
+    }
+}
+
+async fn handle_expiry<JMR>(
+    job: &JobEntity,
+    job_metadata: &JobMetadataEntity,
+    job_metadata_repository: JMR,
+    failure: Option<String>,
+) -> Result<()>
+where
+    JMR: JobMetadataRepositoryPort + Send + Sync,
+{
+    warn!("Job {} expired before it could be dispatched", job.id);
+
+    job_metadata_repository
+        .update_status(create_expired_metadata(job_metadata, failure))
+        .await
+        .map_err(TempusError::Database)
+        .map(|_| increment_jobs_processed("expired"))
 }
 
 fn create_retry_metadata(job_metadata: &JobMetadataEntity) -> JobMetadataEntity {
@@ -107,15 +394,15 @@ async fn handle_retry<JR>(
     job: &JobEntity,
     job_metadata: &JobMetadataEntity,
     job_repository: JR,
-    config: &AppConfig,
+    new_time: DateTime<Utc>,
+    engine: &EngineConfig,
 ) -> Result<()>
 where
     JR: JobRepositoryPort + Send + Sync,
 {
-    let new_time = backoff(job.time, job.retries + 1, config.engine.base_delay_minutes);
     let retry_metadata = create_retry_metadata(job_metadata);
 
-    info!("Retrying job {} (attempt {}/{})", job.id, job.retries + 1, config.engine.retry_attempts);
+    info!("Retrying job {} (attempt {}/{})", job.id, job.retries + 1, engine.retry_attempts);
 
     job_repository
         .handle_retry_transaction(job.id, new_time, retry_metadata)
@@ -144,147 +431,202 @@ where
         .map(|_| increment_jobs_processed("failure"))
 }
 
+/// Transport errors and retryable statuses go through the retry loop; other statuses are terminal,
+/// as are validation errors, which fail the same way on every attempt.
+fn is_retryable(error: &TempusError) -> bool {
+    match error {
+        TempusError::Validation(_) => false,
+        TempusError::HttpStatus { retryable, .. } => *retryable,
+        TempusError::Smtp { retryable, .. } => *retryable,
+        TempusError::Aws { retryable, .. } => *retryable,
+        TempusError::Sql { retryable, .. } => *retryable,
+        TempusError::GrpcStatus { retryable, .. } => *retryable,
+        TempusError::Grpc(_) => false,
+        _ => true,
+    }
+}
+
+fn requested_retry_time(error: &TempusError, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match error {
+        TempusError::HttpStatus { retry_after: Some(retry_after), .. }
+        | TempusError::GrpcStatus { retry_after: Some(retry_after), .. } => {
+            chrono::Duration::from_std(*retry_after).ok().map(|retry_after| now + retry_after)
+        }
+        _ => None,
+    }
+}
+
 async fn handle_failure<JR, JMR>(
     job: JobEntity,
     job_metadata: JobMetadataEntity,
     job_repository: JR,
     job_metadata_repository: JMR,
-    error_msg: String,
-    config: &AppConfig,
+    error: TempusError,
+    engine: &EngineConfig,
 ) -> Result<()>
 where
     JR: JobRepositoryPort + Send + Sync,
     JMR: JobMetadataRepositoryPort + Send + Sync,
 {
-    match should_retry(job.retries, config.engine.retry_attempts) {
-        true => handle_retry(&job, &job_metadata, job_repository, config).await,
-        false => handle_permanent_failure(&job, &job_metadata, job_metadata_repository, error_msg).await,
+    let error_msg = error.to_string();
+
+    if !is_retryable(&error) {
+        warn!("Job {} failed with a terminal error, not retrying: {}", job.id, error_msg);
+    } else if should_retry(job.retries, engine.retry_attempts) {
+        let retry_after = requested_retry_time(&error, Utc::now());
+        return match next_retry_time(&job, engine, retry_after) {
+            Some(new_time) => handle_retry(&job, &job_metadata, job_repository, new_time, engine).await,
+            None => handle_expiry(&job, &job_metadata, job_metadata_repository, Some(error_msg)).await,
+        };
+    }
+
+    if job.schedule.is_some() {
+        warn!("Job {} failed after {} attempts, closing the occurrence: {}", job.id, job.retries, error_msg);
+        return handle_occurrence(&job, &job_metadata, job_repository, Some(error_msg))
+            .await
+            .map(|_| increment_jobs_processed("failure"));
     }
+
+    handle_permanent_failure(&job, &job_metadata, job_metadata_repository, error_msg).await
 }
 
-async fn process_http_job<JMR>(
-    job: &JobEntity,
-    metadata: JobMetadataEntity,
-    target: String,
-    payload: JsonValue,
-    job_metadata_repository: JMR,
-) -> Result<()>
-where
-    JMR: JobMetadataRepositoryPort + Send + Sync + 'static,
-{
-    perform_request(target, payload)
-        .await
-        .and_then(|response| {
-            increment_http_requests(response.status().as_u16());
-            info!("Job {} completed successfully", job.id);
-            Ok(())
-        })
-        .map_err(|e| {
-            error!("Job {} failed: {}", job.id, e);
-            e
-        })?;
+/// Gives up on `execution` once the job timeout elapses, so every executor fails the same way.
+async fn with_timeout<T>(
+    timeout: Option<std::time::Duration>,
+    execution: impl std::future::Future<Output = Result<T>>,
+) -> Result<T> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, execution)
+            .await
+            .map_err(|_| TempusError::Timeout(timeout))?,
+        None => execution.await,
+    }
+}
 
-    handle_success(metadata, job_metadata_repository).await
+/// Runs the executor registered for the job type within the job timeout.
+async fn execute_job(job: &JobEntity, job_executors: &JobExecutorRegistry) -> (JobExecutionEntity, Result<()>) {
+    let execution = JobExecutionEntity::start(job, job.r#type.as_str(), Utc::now());
+
+    let (execution, result) = match job_executors.get(&job.r#type) {
+        Some(executor) => {
+            let outcome = with_timeout(job.execution_timeout(), async {
+                Ok(executor.execute(job, execution.clone()).await)
+            })
+            .await;
+            outcome.unwrap_or_else(|e| (execution, Err(e)))
+        }
+        None => {
+            let error = TempusError::JobProcessing(format!("No executor registered for job type: {}", job.r#type));
+            (execution, Err(error))
+        }
+    };
+
+    match &result {
+        Ok(()) => info!("Job {} completed successfully", job.id),
+        Err(e) => error!("Job {} failed: {}", job.id, e),
+    }
+
+    (execution.finish(&result, Utc::now()), result)
 }
 
-async fn process_kafka_job<JMR>(
-    job: &JobEntity,
-    metadata: JobMetadataEntity,
-    target: String,
-    payload: JsonValue,
-    job_metadata_repository: JMR,
-) -> Result<()>
+/// A failure to record the execution is logged and does not change the job outcome.
+async fn record_execution<JER>(job_execution_repository: &JER, execution: &JobExecutionEntity)
 where
-    JMR: JobMetadataRepositoryPort + Send + Sync + 'static,
+    JER: JobExecutionRepositoryPort + Send + Sync,
 {
-    publish_kafka_message(target, payload)
-        .await
-        .map_err(|e| {
-            error!("Kafka job {} failed: {}", job.id, e);
-            e
-        })?;
-
-    increment_kafka_messages();
-    info!("Kafka job {} completed successfully", job.id);
-    handle_success(metadata, job_metadata_repository).await
+    if let Err(e) = job_execution_repository.save(execution).await {
+        error!("Failed to record execution {} of job {}: {}", execution.id, execution.job_id, e);
+    }
 }
 
-async fn process_job_with_metadata<JR, JMR>(
+async fn handle_job_result<JR, JMR>(
     job: &JobEntity,
-    inner_job: &JobEntity,
     metadata: JobMetadataEntity,
-    target: String,
-    payload: JsonValue,
+    job_result: Result<()>,
     job_repository: JR,
     job_metadata_repository: JMR,
-    config: &AppConfig,
+    engine: &EngineConfig,
 ) -> Result<()>
 where
-    JR: JobRepositoryPort + Send + Sync,
+    JR: JobRepositoryPort + Send + Sync + Clone,
     JMR: JobMetadataRepositoryPort + Send + Sync + Clone + 'static,
 {
-    let job_result = match job.r#type {
-        JobType::Http => process_http_job(job, metadata.clone(), target, payload, job_metadata_repository.clone()).await,
-        JobType::Kafka => process_kafka_job(job, metadata.clone(), target, payload, job_metadata_repository.clone()).await,
+    let job_result = match job_result {
+        Ok(()) => handle_success(job, metadata.clone(), job_repository.clone(), job_metadata_repository.clone()).await,
+        Err(e) => Err(e),
     };
 
     match job_result {
         Ok(_) => Ok(()),
         Err(e) => handle_failure(
-            inner_job.clone(),
+            job.clone(),
             metadata,
             job_repository,
             job_metadata_repository,
-            e.to_string(),
-            config,
+            e,
+            engine,
         ).await,
     }
 }
 
-async fn process_job_by_type<JR, JMR>(
+async fn process_job_by_type<JR, JMR, JER>(
     job: &JobEntity,
-    inner_job: &JobEntity,
-    target: String,
-    payload: JsonValue,
     job_repository: JR,
     job_metadata_repository: JMR,
-    config: &AppConfig,
+    job_execution_repository: JER,
+    job_executors: &JobExecutorRegistry,
+    batch_size: usize,
+    engine: &EngineConfig,
 ) -> Result<()>
 where
-    JR: JobRepositoryPort + Send + Sync,
+    JR: JobRepositoryPort + Send + Sync + Clone,
     JMR: JobMetadataRepositoryPort + Send + Sync + Clone + 'static,
+    JER: JobExecutionRepositoryPort + Send + Sync,
 {
+    let now = Utc::now();
+
     match &job.metadata {
         None => {
             warn!("Metadata is missing for jobId: {}", &job.id);
             Err(TempusError::JobProcessing("Missing job metadata".to_string()))
         }
+        Some(metadata) if is_expired(job, now) => {
+            handle_expiry(job, metadata, job_metadata_repository, None).await
+        }
+        Some(metadata) if is_misfired(job, now, engine) => {
+            handle_misfire(job, metadata, job_repository, job_metadata_repository, now).await
+        }
+        Some(metadata) if should_spread(job, batch_size, now, engine) => {
+            handle_spread(job, metadata, job_repository, spread_time(job, now, engine)).await
+        }
         Some(metadata) => {
-            process_job_with_metadata(
+            let (execution, job_result) = execute_job(job, job_executors).await;
+            record_execution(&job_execution_repository, &execution).await;
+
+            handle_job_result(
                 job,
-                inner_job,
                 metadata.clone(),
-                target,
-                payload,
+                job_result,
                 job_repository,
                 job_metadata_repository,
-                config,
+                engine,
             ).await
         }
     }
 }
 
-impl<JR, JMR> ProcessJobUseCasePort for ProcessJobUseCase<JR, JMR>
+impl<JR, JMR, JER> ProcessJobUseCasePort for ProcessJobUseCase<JR, JMR, JER>
 where
     JR: JobRepositoryPort + Send + Sync + Clone + 'static,
     JMR: JobMetadataRepositoryPort + Send + Sync + Clone + 'static,
+    JER: JobExecutionRepositoryPort + Send + Sync + Clone + 'static,
 {
     async fn execute(&self) -> Result<()> {
         let start_time = Instant::now();
         
         let jobs = self
             .job_repository
-            .find_and_flag_processing(self.config.engine.max_concurrent_jobs)
+            .find_and_flag_processing(self.config.engine.max_concurrent_jobs, self.config.engine.retry_attempts)
             .await
             .map_err(TempusError::Database)?;
 
@@ -309,9 +651,8 @@ where
             
             let job_repository = self.job_repository.clone();
             let job_metadata_repository = self.job_metadata_repository.clone();
-            let job_target = job.target.clone();
-            let job_payload = job.payload.clone();
-            let inner_job = job.clone();
+            let job_execution_repository = self.job_execution_repository.clone();
+            let job_executors = self.job_executors.clone();
             let config = self.config.clone();
 
             let handle = tokio::spawn(async move {
@@ -321,12 +662,12 @@ where
                 
                 let result = process_job_by_type(
                     &job,
-                    &inner_job,
-                    job_target,
-                    job_payload,
                     job_repository,
                     job_metadata_repository,
-                    &config,
+                    job_execution_repository,
+                    &job_executors,
+                    jobs_count,
+                    &config.engine,
                 ).await;
                 
                 if let Err(e) = result {
@@ -354,35 +695,17 @@ where
     }
 }
 
-fn validate_url(url: &str) -> Result<()> {
-    if url.is_empty() {
-        return Err(TempusError::Validation("URL cannot be empty".to_string()));
-    }
-    
-    if !url.starts_with("http://") && !url.starts_with("https://") {
-        return Err(TempusError::Validation("URL must start with http:// or https://".to_string()));
-    }
-    
-    Ok(())
-}
-
-async fn perform_request(target: String, payload: JsonValue) -> Result<Response> {
-    validate_url(&target)?;
-    
-    let client = HTTP_CLIENT.as_ref()
-        .map_err(|e| TempusError::Config(format!("Failed to initialize HTTP client: {}", e)))?;
-    
-    client
-        .post(target)
-        .json(&payload)
-        .send()
-        .await
-        .map_err(TempusError::Http)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+    use crate::domain::calendar::r#enum::calendar_enum::CalendarRoll;
+    use crate::domain::job::entity::job_schedule_entity::JobScheduleEntity;
+    use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use crate::infrastructure::persistence::job::in_memory_job_repository::InMemoryJobRepository;
+    use sea_orm::prelude::async_trait::async_trait;
+    use chrono::Weekday;
 
     #[test]
     fn test_should_retry() {
@@ -394,27 +717,420 @@ mod tests {
 
     #[test]
     fn test_backoff_calculation() {
-        let base_time = chrono::DateTime::from_timestamp(1000, 0).unwrap().naive_utc();
+        let base_time = chrono::DateTime::from_timestamp(1000, 0).unwrap();
         
-        let result1 = backoff(base_time, 0, 2);
+        let result1 = backoff(base_time, Uuid::nil(), 0, 2, RetryJitter::None);
         let expected1 = base_time + chrono::Duration::minutes(2);
         assert_eq!(result1, expected1);
 
-        let result2 = backoff(base_time, 1, 2);
+        let result2 = backoff(base_time, Uuid::nil(), 1, 2, RetryJitter::None);
         let expected2 = base_time + chrono::Duration::minutes(4);
         assert_eq!(result2, expected2);
 
-        let result3 = backoff(base_time, 2, 2);
+        let result3 = backoff(base_time, Uuid::nil(), 2, 2, RetryJitter::None);
         let expected3 = base_time + chrono::Duration::minutes(8);
         assert_eq!(result3, expected3);
     }
 
     #[test]
-    fn test_validate_url() {
-        assert!(validate_url("https://example.com").is_ok());
-        assert!(validate_url("http://example.com").is_ok());
-        assert!(validate_url("").is_err());
-        assert!(validate_url("ftp://example.com").is_err());
-        assert!(validate_url("invalid-url").is_err());
+    fn test_next_retry_time_respects_expiry() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        job.schedule = None;
+        assert_eq!(next_retry_time(&job, &engine_config(MisfirePolicy::FireNow), None), Some(job.time + chrono::Duration::minutes(4)));
+
+        job.expires_at = Some(job.time + chrono::Duration::minutes(30));
+        assert_eq!(next_retry_time(&job, &engine_config(MisfirePolicy::FireNow), None), Some(job.time + chrono::Duration::minutes(4)));
+
+        job.retries = 3;
+        assert_eq!(next_retry_time(&job, &engine_config(MisfirePolicy::FireNow), None), None);
+    }
+
+    #[test]
+    fn test_next_retry_time_uses_retry_after() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        job.schedule = None;
+        let retry_after = job.time + chrono::Duration::minutes(20);
+
+        assert_eq!(next_retry_time(&job, &engine_config(MisfirePolicy::FireNow), Some(retry_after)), Some(retry_after));
+
+        job.expires_at = Some(job.time + chrono::Duration::minutes(10));
+        assert_eq!(next_retry_time(&job, &engine_config(MisfirePolicy::FireNow), Some(retry_after)), None);
+    }
+
+    #[test]
+    fn test_terminal_errors_are_not_retryable() {
+        let terminal = TempusError::HttpStatus { status: 404, retryable: false, retry_after: None };
+        let retryable = TempusError::HttpStatus { status: 503, retryable: true, retry_after: None };
+
+        assert!(!is_retryable(&terminal));
+        assert!(is_retryable(&retryable));
+        assert!(is_retryable(&TempusError::JobProcessing("connection reset".to_string())));
+        assert!(!is_retryable(&TempusError::Validation("Emails need a text or html body".to_string())));
+        assert!(!is_retryable(&TempusError::Smtp { message: "550 mailbox unavailable".to_string(), retryable: false }));
+        assert!(is_retryable(&TempusError::Smtp { message: "451 try again later".to_string(), retryable: true }));
+        assert!(!is_retryable(&TempusError::Aws { message: "queue does not exist".to_string(), retryable: false }));
+        assert!(!is_retryable(&TempusError::Sql { message: "duplicate key".to_string(), retryable: false }));
+        assert!(!is_retryable(&TempusError::Grpc("unknown method".to_string())));
+        assert!(!is_retryable(&TempusError::GrpcStatus {
+            code: 3,
+            message: "invalid argument".to_string(),
+            retryable: false,
+            retry_after: None,
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_with_timeout_fails_slow_execution() {
+        let timeout = std::time::Duration::from_millis(10);
+
+        let slow = with_timeout(Some(timeout), async {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            Ok(())
+        })
+        .await;
+        assert!(matches!(slow, Err(TempusError::Timeout(elapsed)) if elapsed == timeout));
+        assert!(is_retryable(&slow.unwrap_err()));
+
+        assert!(with_timeout(Some(timeout), async { Ok(()) }).await.is_ok());
+        assert!(with_timeout(None, async { Ok(()) }).await.is_ok());
+    }
+
+    struct SleepingExecutor(std::time::Duration);
+
+    #[async_trait]
+    impl JobExecutor for SleepingExecutor {
+        async fn execute(&self, _job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+            tokio::time::sleep(self.0).await;
+            (JobExecutionEntity { status_code: Some(204), ..execution }, Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_job_dispatches_by_type() {
+        let job_executors = JobExecutorRegistry::new()
+            .register("http", SleepingExecutor(std::time::Duration::ZERO))
+            .register("slow", SleepingExecutor(std::time::Duration::from_secs(5)));
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+
+        let (execution, result) = execute_job(&job, &job_executors).await;
+        assert!(result.is_ok());
+        assert_eq!(execution.executor, "http");
+        assert_eq!(execution.status_code, Some(204));
+
+        job.r#type = JobType::new("slow");
+        job.timeout = Some(chrono::Duration::milliseconds(10));
+        let (execution, result) = execute_job(&job, &job_executors).await;
+        assert!(matches!(result, Err(TempusError::Timeout(_))));
+        assert_eq!(execution.status_code, None);
+        assert!(execution.error.is_some());
+
+        job.r#type = JobType::new("sms");
+        let (_, result) = execute_job(&job, &job_executors).await;
+        assert!(matches!(result, Err(TempusError::JobProcessing(_))));
+    }
+
+    struct FailingExecutor;
+
+    #[async_trait]
+    impl JobExecutor for FailingExecutor {
+        async fn execute(&self, _job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+            (execution, Err(TempusError::JobProcessing("connection refused".to_string())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recurring_job_is_rescheduled_after_exhausting_retries() {
+        let engine = engine_config(MisfirePolicy::FireNow);
+        let job_executors = JobExecutorRegistry::new().register("http", FailingExecutor);
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        job.metadata = Some(JobMetadataEntity {
+            job_id: job.id,
+            status: JobMetadataStatus::Scheduled,
+            failure: None,
+            processed_at: None,
+        });
+        let (job_id, scheduled_at) = (job.id, job.scheduled_at);
+        let repository = InMemoryJobRepository::with_job(job);
+
+        for attempt in 0..=engine.retry_attempts {
+            let claimed = repository.find_and_flag_processing(1, engine.retry_attempts).await.unwrap();
+            assert_eq!(claimed.len(), 1, "attempt {} was not claimed", attempt);
+
+            process_job_by_type(&claimed[0], repository.clone(), repository.clone(), repository.clone(), &job_executors, 1, &engine)
+                .await
+                .unwrap();
+        }
+
+        let job = repository.job(job_id).unwrap();
+        let metadata = job.metadata.unwrap();
+        assert!(matches!(metadata.status, JobMetadataStatus::Scheduled));
+        assert_eq!(metadata.failure.as_deref(), Some("Job processing error: connection refused"));
+        assert_eq!(job.retries, 0);
+        assert_eq!(job.occurrences, 1);
+        assert_eq!(job.scheduled_at, scheduled_at + chrono::Duration::minutes(15));
+        assert_eq!(job.time, job.scheduled_at);
+    }
+
+    fn weekday_calendar() -> CalendarEntity {
+        CalendarEntity::new(
+            "weekdays".to_string(),
+            vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            vec![],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_next_occurrence_rolls_forward_over_weekend() {
+        // 1970-01-02 is a Friday.
+        let friday = chrono::DateTime::from_timestamp(0, 0).unwrap() + chrono::Duration::days(1);
+        let mut job = recurring_job(JobScheduleEntity::cron("0 0 9 * * *", None).unwrap());
+        job.scheduled_at = friday + chrono::Duration::hours(9);
+        job.calendar = Some(weekday_calendar());
+
+        let monday = job.scheduled_at + chrono::Duration::days(3);
+        assert_eq!(next_occurrence(&job).unwrap(), Some(monday));
+
+        job.scheduled_at = monday;
+        assert_eq!(next_occurrence(&job).unwrap(), Some(monday + chrono::Duration::days(1)));
+    }
+
+    #[test]
+    fn test_next_occurrence_rolls_backward_without_repeating() {
+        let friday = chrono::DateTime::from_timestamp(0, 0).unwrap() + chrono::Duration::days(1);
+        let mut job = recurring_job(JobScheduleEntity::cron("0 0 9 * * *", None).unwrap());
+        job.scheduled_at = friday + chrono::Duration::hours(9);
+        job.calendar = Some(weekday_calendar());
+        job.calendar_roll = CalendarRoll::Backward;
+
+        assert_eq!(next_occurrence(&job).unwrap(), Some(job.scheduled_at + chrono::Duration::days(3)));
+    }
+
+    #[test]
+    fn test_next_occurrence_ignores_retry_time() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        job.time = job.scheduled_at + chrono::Duration::minutes(20);
+
+        assert_eq!(next_occurrence(&job).unwrap(), Some(job.scheduled_at + chrono::Duration::minutes(15)));
     }
+
+    #[test]
+    fn test_next_retry_time_rolls_to_working_day() {
+        let friday = chrono::DateTime::from_timestamp(0, 0).unwrap() + chrono::Duration::days(1);
+        let mut job = recurring_job(JobScheduleEntity::cron("0 0 9 * * *", None).unwrap());
+        job.time = friday + chrono::Duration::hours(23);
+        job.calendar = Some(weekday_calendar());
+
+        assert_eq!(
+            next_retry_time(&job, &EngineConfig { base_delay_minutes: 60, ..engine_config(MisfirePolicy::FireNow) }, None),
+            Some(job.time + chrono::Duration::days(2) + chrono::Duration::hours(2))
+        );
+    }
+
+    #[test]
+    fn test_backoff_jitter_is_seeded_by_job_id() {
+        let base_time = chrono::DateTime::from_timestamp(1000, 0).unwrap();
+        let job_id = uuid::Uuid::new_v4();
+
+        for jitter in [RetryJitter::Full, RetryJitter::Decorrelated] {
+            assert_eq!(
+                backoff(base_time, job_id, 2, 2, jitter),
+                backoff(base_time, job_id, 2, 2, jitter)
+            );
+        }
+    }
+
+    #[test]
+    fn test_full_jitter_stays_within_exponential_delay() {
+        for _ in 0..100 {
+            let delay_seconds = calculate_delay_seconds(uuid::Uuid::new_v4(), 3, 2, RetryJitter::Full);
+            assert!((0..16 * 60).contains(&delay_seconds));
+        }
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_stays_between_base_and_exponential_delay() {
+        for _ in 0..100 {
+            let delay_seconds = calculate_delay_seconds(uuid::Uuid::new_v4(), 3, 2, RetryJitter::Decorrelated);
+            assert!((2 * 60..=16 * 60).contains(&delay_seconds));
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence_applies_jitter() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        job.jitter = Some(chrono::Duration::minutes(5));
+
+        let next_time = next_occurrence(&job).unwrap().unwrap();
+        assert!(next_time >= job.time + chrono::Duration::minutes(15));
+        assert!(next_time < job.time + chrono::Duration::minutes(20));
+        assert_eq!(next_occurrence(&job).unwrap(), Some(next_time));
+    }
+
+    #[test]
+    fn test_spread_overdue_jobs() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        let now = job.time + chrono::Duration::hours(1);
+        let engine = EngineConfig {
+            spread_overdue_secs: 300,
+            ..engine_config(MisfirePolicy::FireNow)
+        };
+
+        assert!(!should_spread(&job, 1, now, &engine_config(MisfirePolicy::FireNow)));
+        assert!(!should_spread(&job, 1, job.time + chrono::Duration::seconds(30), &engine));
+        assert!(should_spread(&job, 1, now, &engine));
+
+        let new_time = spread_time(&job, now, &engine);
+        assert!(new_time >= now && new_time < now + chrono::Duration::minutes(5));
+
+        job.expires_at = Some(now);
+        assert_eq!(spread_time(&job, now, &engine), now);
+    }
+
+    #[test]
+    fn test_spread_due_burst() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        let now = job.time + chrono::Duration::seconds(1);
+        let engine = EngineConfig {
+            spread_overdue_secs: 300,
+            spread_batch_threshold: 100,
+            ..engine_config(MisfirePolicy::FireNow)
+        };
+
+        assert!(!should_spread(&job, 100, now, &engine));
+        assert!(should_spread(&job, 101, now, &engine));
+        assert!(!should_spread(&job, 101, now, &EngineConfig { spread_batch_threshold: 0, ..engine.clone() }));
+
+        // Already spread once, so a later burst runs it.
+        job.time = spread_time(&job, now, &engine);
+        assert!(!should_spread(&job, 101, job.time, &engine));
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        let now = job.time + chrono::Duration::minutes(31);
+        assert!(!is_expired(&job, now));
+
+        job.expires_at = Some(job.time + chrono::Duration::minutes(30));
+        assert!(is_expired(&job, now));
+        assert!(!is_expired(&job, job.time + chrono::Duration::minutes(30)));
+    }
+
+    fn recurring_job(schedule: JobScheduleEntity) -> JobEntity {
+        JobEntity {
+            schedule: Some(schedule),
+            ..JobEntity::fixture(JobType::HTTP, "https://example.com")
+        }
+    }
+
+    fn engine_config(misfire_policy: MisfirePolicy) -> EngineConfig {
+        EngineConfig {
+            max_concurrent_jobs: 10,
+            retry_attempts: 3,
+            base_delay_minutes: 2,
+            misfire_policy,
+            misfire_threshold_secs: 60,
+            retry_jitter: RetryJitter::None,
+            spread_overdue_secs: 0,
+            spread_batch_threshold: 0,
+        }
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        assert_eq!(next_occurrence(&job).unwrap(), Some(job.time + chrono::Duration::minutes(15)));
+
+        job.schedule = None;
+        assert_eq!(next_occurrence(&job).unwrap(), None);
+    }
+
+    #[test]
+    fn test_next_occurrence_respects_bounds() {
+        let start = chrono::DateTime::from_timestamp(0, 0).unwrap();
+        let mut job = recurring_job(JobScheduleEntity::interval(90, start).unwrap());
+        assert_eq!(next_occurrence(&job).unwrap(), Some(start + chrono::Duration::seconds(90)));
+
+        job.max_occurrences = Some(2);
+        job.occurrences = 1;
+        assert_eq!(next_occurrence(&job).unwrap(), None);
+
+        job.max_occurrences = None;
+        job.ends_at = Some(start + chrono::Duration::seconds(60));
+        assert_eq!(next_occurrence(&job).unwrap(), None);
+
+        job.ends_at = Some(start + chrono::Duration::seconds(90));
+        assert_eq!(next_occurrence(&job).unwrap(), Some(start + chrono::Duration::seconds(90)));
+    }
+
+    #[test]
+    fn test_is_misfired() {
+        let mut job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        let on_time = job.time + chrono::Duration::seconds(30);
+        let six_hours_late = job.time + chrono::Duration::hours(6);
+
+        assert!(!is_misfired(&job, six_hours_late, &engine_config(MisfirePolicy::FireNow)));
+        assert!(!is_misfired(&job, on_time, &engine_config(MisfirePolicy::Skip)));
+        assert!(is_misfired(&job, six_hours_late, &engine_config(MisfirePolicy::Skip)));
+
+        job.misfire_policy = Some(MisfirePolicy::FireIfWithin(std::time::Duration::from_secs(7 * 60 * 60)));
+        assert!(!is_misfired(&job, six_hours_late, &engine_config(MisfirePolicy::Skip)));
+
+        job.misfire_policy = Some(MisfirePolicy::FireIfWithin(std::time::Duration::from_secs(60 * 60)));
+        assert!(is_misfired(&job, six_hours_late, &engine_config(MisfirePolicy::FireNow)));
+    }
+
+    #[test]
+    fn test_next_occurrence_after_skips_missed_occurrences() {
+        let job = recurring_job(JobScheduleEntity::cron("0 */15 * * * *", None).unwrap());
+        let now = job.time + chrono::Duration::minutes(50);
+
+        assert_eq!(next_occurrence_after(&job, now).unwrap(), Some(job.time + chrono::Duration::minutes(60)));
+    }
+
+    #[test]
+    fn test_create_skipped_metadata() {
+        let metadata = JobMetadataEntity {
+            job_id: uuid::Uuid::new_v4(),
+            status: JobMetadataStatus::Processing,
+            failure: None,
+            processed_at: None,
+        };
+
+        let rescheduled = create_skipped_metadata(&metadata, Some(Utc::now()));
+        assert!(matches!(rescheduled.status, JobMetadataStatus::Scheduled));
+
+        let skipped = create_skipped_metadata(&metadata, None);
+        assert!(matches!(skipped.status, JobMetadataStatus::Skipped));
+        assert!(skipped.processed_at.is_some());
+    }
+
+    #[test]
+    fn test_create_occurrence_metadata() {
+        let metadata = JobMetadataEntity {
+            job_id: uuid::Uuid::new_v4(),
+            status: JobMetadataStatus::Processing,
+            failure: None,
+            processed_at: None,
+        };
+        let next_time = Some(Utc::now());
+
+        let scheduled = create_occurrence_metadata(&metadata, next_time, Some("boom".to_string()));
+        assert_eq!(scheduled.job_id, metadata.job_id);
+        assert!(matches!(scheduled.status, JobMetadataStatus::Scheduled));
+        assert_eq!(scheduled.failure.as_deref(), Some("boom"));
+        assert!(scheduled.processed_at.is_some());
+
+        let completed = create_occurrence_metadata(&metadata, None, None);
+        assert!(matches!(completed.status, JobMetadataStatus::Completed));
+        assert_eq!(completed.failure, None);
+
+        let failed = create_occurrence_metadata(&metadata, None, Some("boom".to_string()));
+        assert!(matches!(failed.status, JobMetadataStatus::Failed));
+        assert_eq!(failed.failure.as_deref(), Some("boom"));
+    }
+
+
 }
\ No newline at end of file
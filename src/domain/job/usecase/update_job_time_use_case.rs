@@ -1,7 +1,8 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Duration, Utc};
 use log::info;
 use uuid::Uuid;
 
+use crate::domain::job::entity::job_entity::JobEntity;
 use crate::domain::job::port::driven::job_repository_port::JobRepositoryPort;
 use crate::error::{TempusError, Result};
 
@@ -14,8 +15,39 @@ impl<R: JobRepositoryPort> UpdateJobTimeUseCase<R> {
         Self { job_repository }
     }
 
-    pub async fn execute(&self, job_id: Uuid, new_time: NaiveDateTime) -> Result<()> {
-        let job_updated = self.job_repository.update_time_unprocessed(job_id, new_time).await
+    pub async fn execute(&self, job_id: Uuid, new_time: DateTime<Utc>) -> Result<()> {
+        let job = self.find_job(job_id).await?;
+        self.update_time(&job, job.roll_to_working_day(new_time)).await
+    }
+
+    pub async fn postpone(&self, job_id: Uuid, postpone_by: Duration) -> Result<()> {
+        let new_time = self.job_repository
+            .postpone_unprocessed(job_id, |job| {
+                let new_time = job.roll_to_working_day(job.time + postpone_by);
+                validate_expiry(job, new_time)?;
+                Ok(new_time)
+            })
+            .await?
+            .ok_or_else(|| TempusError::Validation(
+                "Job not found or already processed".to_string()
+            ))?;
+
+        info!("Job time postponed successfully for ID: {} to: {}", job_id, new_time);
+        Ok(())
+    }
+
+    async fn find_job(&self, job_id: Uuid) -> Result<JobEntity> {
+        self.job_repository.find_by_id(job_id).await
+            .map_err(TempusError::from)?
+            .ok_or_else(|| TempusError::Validation(
+                "Job not found or already processed".to_string()
+            ))
+    }
+
+    async fn update_time(&self, job: &JobEntity, new_time: DateTime<Utc>) -> Result<()> {
+        validate_expiry(job, new_time)?;
+
+        let job_updated = self.job_repository.update_time_unprocessed(job.id, new_time).await
             .map_err(TempusError::from)?;
 
         if !job_updated {
@@ -24,7 +56,33 @@ impl<R: JobRepositoryPort> UpdateJobTimeUseCase<R> {
             ));
         }
 
-        info!("Job time updated successfully for ID: {} to: {}", job_id, new_time);
+        info!("Job time updated successfully for ID: {} to: {}", job.id, new_time);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+fn validate_expiry(job: &JobEntity, new_time: DateTime<Utc>) -> Result<()> {
+    if job.expires_at.is_some_and(|expires_at| new_time > expires_at) {
+        return Err(TempusError::Conflict(
+            "Job cannot be moved past its expires_at".to_string()
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+
+    #[test]
+    fn test_validate_expiry() {
+        let mut job = JobEntity::fixture(JobType::HTTP, "https://example.com");
+        assert!(validate_expiry(&job, job.time + Duration::days(365)).is_ok());
+
+        job.expires_at = Some(job.time + Duration::minutes(30));
+        assert!(validate_expiry(&job, job.time + Duration::minutes(30)).is_ok());
+        assert!(matches!(validate_expiry(&job, job.time + Duration::minutes(31)), Err(TempusError::Conflict(_))));
+    }
+}
@@ -1,8 +1,22 @@
 use crate::config::app_config::AppConfig;
 use crate::config::connection::connect_with_retry;
+use crate::domain::job::port::driven::job_executor_port::{JobExecutor, JobExecutorRegistry};
 use crate::domain::job::port::driver::process_job_use_case_port::ProcessJobUseCasePort;
+use crate::domain::job::r#enum::job_enum::JobType;
 use crate::domain::job::usecase::process_job_use_case::ProcessJobUseCase;
 use crate::error::Result;
+use crate::infrastructure::amqp::amqp_job_executor::AmqpJobExecutor;
+use crate::infrastructure::aws::aws_client::AwsService;
+use crate::infrastructure::aws::aws_job_executor::AwsJobExecutor;
+use crate::infrastructure::command::command_job_executor::CommandJobExecutor;
+use crate::infrastructure::email::email_job_executor::EmailJobExecutor;
+use crate::infrastructure::grpc::grpc_job_executor::GrpcJobExecutor;
+use crate::infrastructure::http::http_job_executor::HttpJobExecutor;
+use crate::infrastructure::kafka::kafka_job_executor::KafkaJobExecutor;
+use crate::infrastructure::nats::nats_job_executor::NatsJobExecutor;
+use crate::infrastructure::redis::redis_job_executor::RedisJobExecutor;
+use crate::infrastructure::sql::sql_job_executor::SqlJobExecutor;
+use crate::infrastructure::persistence::job::job_execution_repository::JobExecutionRepository;
 use crate::infrastructure::persistence::job::job_metadata_repository::JobMetadataRepository;
 use crate::infrastructure::persistence::job::job_repository::JobRepository;
 use log::{error, info, warn};
@@ -17,12 +31,37 @@ pub trait TempusEnginePort {
 
 pub struct TempusEngine {
     config: AppConfig,
+    job_executors: JobExecutorRegistry,
+}
+
+/// The executors Tempus ships with: `http`, `kafka`, `amqp`, `nats`, `redis`, `sqs`, `sns`,
+/// `command`, `email`, `sql` and `grpc`.
+pub fn default_job_executors(config: &AppConfig) -> Result<JobExecutorRegistry> {
+    Ok(JobExecutorRegistry::new()
+        .register(JobType::HTTP, HttpJobExecutor::new(config.http.clone())?)
+        .register(JobType::KAFKA, KafkaJobExecutor::new(config.kafka.clone()))
+        .register(JobType::AMQP, AmqpJobExecutor::new(config.amqp.clone()))
+        .register(JobType::NATS, NatsJobExecutor::new(config.nats.clone()))
+        .register(JobType::REDIS, RedisJobExecutor::new(config.redis.clone()))
+        .register(JobType::SQS, AwsJobExecutor::new(AwsService::Sqs, config.aws.clone()))
+        .register(JobType::SNS, AwsJobExecutor::new(AwsService::Sns, config.aws.clone()))
+        .register(JobType::COMMAND, CommandJobExecutor::new(config.command.clone()))
+        .register(JobType::EMAIL, EmailJobExecutor::new(config.smtp.clone())?)
+        .register(JobType::SQL, SqlJobExecutor::new(config.sql.clone(), config.database.clone()))
+        .register(JobType::GRPC, GrpcJobExecutor::new(config.grpc.clone())?))
 }
 
 impl TempusEngine {
     pub fn new() -> Result<Self> {
         let config = AppConfig::load()?;
-        Ok(Self { config })
+        let job_executors = default_job_executors(&config)?;
+        Ok(Self { config, job_executors })
+    }
+
+    /// Delivers jobs of type `name` with `executor`, replacing a built-in executor of the same name.
+    pub fn with_executor(mut self, name: &str, executor: impl JobExecutor + 'static) -> Self {
+        self.job_executors = self.job_executors.register(name, executor);
+        self
     }
 }
 
@@ -33,7 +72,14 @@ impl TempusEnginePort for TempusEngine {
         let database = connect_with_retry(&self.config).await?;
         let job_repository = JobRepository::new(database.clone());
         let job_metadata_repository = JobMetadataRepository::new(database.clone());
-        let usecase = ProcessJobUseCase::new(job_repository, job_metadata_repository, &self.config);
+        let job_execution_repository = JobExecutionRepository::new(database.clone());
+        let usecase = ProcessJobUseCase::new(
+            job_repository,
+            job_metadata_repository,
+            job_execution_repository,
+            self.job_executors.clone(),
+            &self.config,
+        );
 
         let shutdown_token = CancellationToken::new();
         let shutdown_token_clone = shutdown_token.clone();
@@ -8,6 +8,17 @@ pub enum TempusError {
     #[error("HTTP request failed: {0}")]
     Http(#[from] reqwest::Error),
     
+    /// A response outside the job's success statuses. `retry_after` comes from the `Retry-After` header.
+    #[error("HTTP request returned status {status}")]
+    HttpStatus {
+        status: u16,
+        retryable: bool,
+        retry_after: Option<std::time::Duration>,
+    },
+    
+    #[error("Execution timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    
     #[error("Configuration error: {0}")]
     Config(String),
     
@@ -20,6 +31,9 @@ pub enum TempusError {
     #[error("Validation error: {0}")]
     Validation(String),
     
+    #[error("Conflict: {0}")]
+    Conflict(String),
+    
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
     
@@ -29,6 +43,53 @@ pub enum TempusError {
     #[error("Kafka error: {0}")]
     Kafka(String),
     
+    #[error("AMQP error: {0}")]
+    Amqp(String),
+    
+    #[error("NATS error: {0}")]
+    Nats(String),
+    
+    #[error("Redis error: {0}")]
+    Redis(String),
+    
+    /// Client (4xx) errors other than throttling are not retryable.
+    #[error("AWS error: {message}")]
+    Aws {
+        message: String,
+        retryable: bool,
+    },
+    
+    #[error("Command error: {0}")]
+    Command(String),
+    
+    /// Permanent (5xx) SMTP replies and messages that cannot be built are not retryable.
+    #[error("SMTP error: {message}")]
+    Smtp {
+        message: String,
+        retryable: bool,
+    },
+    
+    /// Missing statements and parameters, and data, constraint or syntax errors (SQLSTATE classes
+    /// 22, 23 and 42) are not retryable.
+    #[error("SQL error: {message}")]
+    Sql {
+        message: String,
+        retryable: bool,
+    },
+    
+    /// A payload or method that does not match the descriptor set, which retries cannot fix.
+    #[error("gRPC error: {0}")]
+    Grpc(String),
+    
+    /// A non-OK status. `retry_after` comes from the `grpc-retry-pushback-ms` trailer.
+    #[error("gRPC call returned status {code}: {message}")]
+    GrpcStatus {
+        code: i32,
+        message: String,
+        retryable: bool,
+        retry_after: Option<std::time::Duration>,
+    },
+    
     #[error("Metrics error: {0}")]
     Metrics(String),
 }
@@ -0,0 +1,78 @@
+use crate::config::app_config::AmqpConfig;
+use crate::domain::job::entity::job_amqp_message_entity::JobAmqpMessageEntity;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::Result;
+use crate::infrastructure::amqp::amqp_publisher::AmqpPublisher;
+use crate::infrastructure::delivery::{delivery_headers, idempotency_key};
+use crate::infrastructure::metrics::increment_amqp_messages;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+
+/// Publishes `amqp` jobs to the exchange named by their target.
+pub struct AmqpJobExecutor {
+    publisher: AmqpPublisher,
+}
+
+impl AmqpJobExecutor {
+    pub fn new(config: AmqpConfig) -> Self {
+        Self {
+            publisher: AmqpPublisher::new(config),
+        }
+    }
+}
+
+fn with_amqp_delivery_headers(job: &JobEntity) -> Result<JobAmqpMessageEntity> {
+    let mut amqp_message = job.options::<JobAmqpMessageEntity>()?.unwrap_or_default();
+    amqp_message.headers.extend(delivery_headers(job));
+    Ok(amqp_message)
+}
+
+#[async_trait]
+impl JobExecutor for AmqpJobExecutor {
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        Ok(serde_json::to_value(JobAmqpMessageEntity::from_options(&request.options)?)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let amqp_message = match with_amqp_delivery_headers(job) {
+            Ok(amqp_message) => amqp_message,
+            Err(e) => return (execution, Err(e)),
+        };
+        let message_id = idempotency_key(job);
+
+        let result = self
+            .publisher
+            .publish(&job.target, &job.payload, &amqp_message, &message_id, job.execution_timeout())
+            .await;
+
+        if result.is_ok() {
+            increment_amqp_messages();
+        }
+
+        (execution, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use crate::infrastructure::delivery::{ATTEMPT_HEADER, JOB_ID_HEADER};
+
+    #[test]
+    fn test_with_amqp_delivery_headers() {
+        let job = JobEntity {
+            retries: 1,
+            ..JobEntity::fixture(JobType::AMQP, "orders")
+        };
+
+        let amqp_message = with_amqp_delivery_headers(&job).unwrap();
+
+        assert!(amqp_message.persistent);
+        assert_eq!(amqp_message.headers.get(JOB_ID_HEADER), Some(&job.id.to_string()));
+        assert_eq!(amqp_message.headers.get(ATTEMPT_HEADER).map(String::as_str), Some("2"));
+    }
+}
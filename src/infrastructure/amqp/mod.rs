@@ -0,0 +1,2 @@
+pub mod amqp_job_executor;
+pub mod amqp_publisher;
\ No newline at end of file
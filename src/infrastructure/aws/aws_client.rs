@@ -0,0 +1,93 @@
+use crate::config::app_config::AwsConfig;
+use crate::error::TempusError;
+use aws_config::{BehaviorVersion, Region, SdkConfig};
+use aws_runtime::retries::classifiers::THROTTLING_ERRORS;
+use aws_sdk_sqs::config::Credentials;
+use aws_sdk_sqs::error::{DisplayErrorContext, ProvideErrorMetadata, SdkError};
+use std::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AwsService {
+    Sqs,
+    Sns,
+}
+
+impl AwsService {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AwsService::Sqs => "sqs",
+            AwsService::Sns => "sns",
+        }
+    }
+
+    fn endpoint_url<'a>(&self, config: &'a AwsConfig) -> &'a str {
+        match self {
+            AwsService::Sqs => &config.sqs_endpoint_url,
+            AwsService::Sns => &config.sns_endpoint_url,
+        }
+    }
+}
+
+/// SDK settings for `service`. Without a configured access key the SDK's default credential
+/// chain is used: environment, shared profile, web identity, ECS or EC2 instance credentials.
+pub async fn load_sdk_config(service: AwsService, config: &AwsConfig) -> SdkConfig {
+    let mut loader = aws_config::defaults(BehaviorVersion::latest()).region(Region::new(config.region.clone()));
+
+    if !config.access_key_id.is_empty() {
+        loader = loader.credentials_provider(Credentials::new(
+            &config.access_key_id,
+            &config.secret_access_key,
+            (!config.session_token.is_empty()).then(|| config.session_token.clone()),
+            None,
+            "tempus",
+        ));
+    }
+
+    let endpoint_url = service.endpoint_url(config);
+    if !endpoint_url.is_empty() {
+        loader = loader.endpoint_url(endpoint_url);
+    }
+
+    loader.load().await
+}
+
+/// Requests that cannot be built and client (4xx) errors other than throttling fail the job
+/// without retries; timeouts, connection failures and server errors are retried.
+pub fn sdk_error<E>(service: AwsService, action: &str, error: SdkError<E>) -> TempusError
+where
+    E: ProvideErrorMetadata + Error + Send + Sync + 'static,
+{
+    let retryable = match &error {
+        SdkError::ConstructionFailure(_) => false,
+        _ => is_retryable(
+            error.raw_response().map(|response| response.status().as_u16()),
+            error.as_service_error().and_then(ProvideErrorMetadata::code),
+        ),
+    };
+
+    TempusError::Aws {
+        message: format!("{} {} failed: {}", service.name(), action, DisplayErrorContext(&error)),
+        retryable,
+    }
+}
+
+fn is_retryable(status: Option<u16>, code: Option<&str>) -> bool {
+    let throttled = status == Some(429) || code.is_some_and(|code| THROTTLING_ERRORS.contains(&code));
+    throttled || !status.is_some_and(|status| (400..500).contains(&status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable(None, None));
+        assert!(is_retryable(Some(500), Some("InternalError")));
+        assert!(is_retryable(Some(400), Some("Throttling")));
+        assert!(is_retryable(Some(403), Some("RequestThrottled")));
+        assert!(is_retryable(Some(429), None));
+        assert!(!is_retryable(Some(400), Some("AWS.SimpleQueueService.NonExistentQueue")));
+        assert!(!is_retryable(Some(403), Some("AccessDenied")));
+    }
+}
@@ -0,0 +1,260 @@
+use crate::config::app_config::AwsConfig;
+use crate::domain::job::entity::job_aws_message_entity::{is_fifo, JobAwsMessageEntity};
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::aws::aws_client::{load_sdk_config, sdk_error, AwsService};
+use crate::infrastructure::delivery::{delivery_headers, idempotency_key};
+use crate::infrastructure::metrics::increment_aws_messages;
+use log::debug;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::OnceCell;
+
+/// Sends `sqs` jobs to the queue URL and `sns` jobs to the topic ARN named by their target.
+pub struct AwsJobExecutor {
+    service: AwsService,
+    config: AwsConfig,
+    client: OnceCell<AwsClient>,
+}
+
+enum AwsClient {
+    Sqs(aws_sdk_sqs::Client),
+    Sns(aws_sdk_sns::Client),
+}
+
+/// What Tempus adds to the job's message options.
+#[derive(Debug, PartialEq)]
+struct AwsMessage {
+    attributes: BTreeMap<String, String>,
+    message_group_id: Option<String>,
+    message_deduplication_id: Option<String>,
+}
+
+impl AwsJobExecutor {
+    pub fn new(service: AwsService, config: AwsConfig) -> Self {
+        Self {
+            service,
+            config,
+            client: OnceCell::new(),
+        }
+    }
+
+    /// Loading credentials may call out to a profile or instance metadata, so the client is
+    /// created on first use.
+    async fn client(&self) -> &AwsClient {
+        self.client
+            .get_or_init(|| async {
+                let sdk_config = load_sdk_config(self.service, &self.config).await;
+                match self.service {
+                    AwsService::Sqs => AwsClient::Sqs(aws_sdk_sqs::Client::new(&sdk_config)),
+                    AwsService::Sns => AwsClient::Sns(aws_sdk_sns::Client::new(&sdk_config)),
+                }
+            })
+            .await
+    }
+
+    /// Returns the id AWS assigned to the message.
+    async fn send(&self, target: &str, body: String, message: AwsMessage) -> Result<String> {
+        let message_id = match self.client().await {
+            AwsClient::Sqs(client) => {
+                let attributes = message
+                    .attributes
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let value = aws_sdk_sqs::types::MessageAttributeValue::builder()
+                            .data_type("String")
+                            .string_value(value)
+                            .build()
+                            .map_err(|e| TempusError::Aws {
+                                message: e.to_string(),
+                                retryable: false,
+                            })?;
+                        Ok((name, value))
+                    })
+                    .collect::<Result<HashMap<_, _>>>()?;
+
+                client
+                    .send_message()
+                    .queue_url(target)
+                    .message_body(body)
+                    .set_message_attributes(Some(attributes))
+                    .set_message_group_id(message.message_group_id)
+                    .set_message_deduplication_id(message.message_deduplication_id)
+                    .send()
+                    .await
+                    .map_err(|e| sdk_error(self.service, "SendMessage", e))?
+                    .message_id
+            }
+            AwsClient::Sns(client) => {
+                let attributes = message
+                    .attributes
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let value = aws_sdk_sns::types::MessageAttributeValue::builder()
+                            .data_type("String")
+                            .string_value(value)
+                            .build()
+                            .map_err(|e| TempusError::Aws {
+                                message: e.to_string(),
+                                retryable: false,
+                            })?;
+                        Ok((name, value))
+                    })
+                    .collect::<Result<HashMap<_, _>>>()?;
+
+                client
+                    .publish()
+                    .topic_arn(target)
+                    .message(body)
+                    .set_message_attributes(Some(attributes))
+                    .set_message_group_id(message.message_group_id)
+                    .set_message_deduplication_id(message.message_deduplication_id)
+                    .send()
+                    .await
+                    .map_err(|e| sdk_error(self.service, "Publish", e))?
+                    .message_id
+            }
+        };
+
+        message_id.ok_or_else(|| TempusError::Aws {
+            message: format!("{} response has no MessageId", self.service.name()),
+            retryable: true,
+        })
+    }
+}
+
+/// Tempus attributes replace user attributes of the same name, and FIFO messages without a
+/// deduplication id get one that is stable across retries of an occurrence.
+fn message(job: &JobEntity, aws_message: JobAwsMessageEntity) -> AwsMessage {
+    let mut attributes = aws_message.attributes;
+    attributes.extend(delivery_headers(job));
+
+    let message_deduplication_id = aws_message
+        .message_deduplication_id
+        .or_else(|| is_fifo(&job.target).then(|| idempotency_key(job)));
+
+    AwsMessage {
+        attributes,
+        message_group_id: aws_message.message_group_id,
+        message_deduplication_id,
+    }
+}
+
+#[async_trait]
+impl JobExecutor for AwsJobExecutor {
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        Ok(serde_json::to_value(JobAwsMessageEntity::from_options(&request.options, &request.target)?)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let result = async {
+            let aws_message = job.options::<JobAwsMessageEntity>()?.unwrap_or_default();
+            let body = serde_json::to_string(&job.payload)?;
+
+            let timeout = job.execution_timeout().unwrap_or(self.config.request_timeout());
+            let message_id = tokio::time::timeout(timeout, self.send(&job.target, body, message(job, aws_message)))
+                .await
+                .map_err(|_| TempusError::Timeout(timeout))??;
+
+            debug!("Job {} sent to {} as message {}", job.id, job.target, message_id);
+            increment_aws_messages(self.service.name());
+            Ok(())
+        }
+        .await;
+
+        (execution, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use crate::infrastructure::delivery::{ATTEMPT_HEADER, JOB_ID_HEADER};
+
+    fn job(target: &str) -> JobEntity {
+        JobEntity {
+            retries: 1,
+            occurrences: 3,
+            ..JobEntity::fixture(JobType::SQS, target)
+        }
+    }
+
+    #[test]
+    fn test_message() {
+        let job = job("http://localhost:4566/000000000000/orders");
+        let aws_message = JobAwsMessageEntity::new(
+            &job.target,
+            BTreeMap::from([
+                ("trace-id".to_string(), "abc".to_string()),
+                (JOB_ID_HEADER.to_string(), "spoofed".to_string()),
+            ]),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let message = message(&job, aws_message);
+
+        assert_eq!(message.attributes.len(), 3);
+        assert_eq!(message.attributes.get("trace-id").map(String::as_str), Some("abc"));
+        assert_eq!(message.attributes.get(JOB_ID_HEADER), Some(&job.id.to_string()));
+        assert_eq!(message.attributes.get(ATTEMPT_HEADER).map(String::as_str), Some("2"));
+        assert_eq!(message.message_group_id, None);
+        assert_eq!(message.message_deduplication_id, None);
+    }
+
+    #[test]
+    fn test_fifo_message() {
+        let job = job("arn:aws:sns:us-east-1:000000000000:orders.fifo");
+        let aws_message = JobAwsMessageEntity::new(&job.target, BTreeMap::new(), Some("customer-1".to_string()), None).unwrap();
+
+        let message = message(&job, aws_message);
+
+        assert_eq!(message.message_group_id.as_deref(), Some("customer-1"));
+        assert_eq!(message.message_deduplication_id, Some(format!("{}:3", job.id)));
+    }
+
+    /// Run with `cargo test -- --ignored` against the LocalStack service in docker-compose.yml.
+    #[tokio::test]
+    #[ignore = "requires a local SQS-compatible service"]
+    async fn test_send_to_local_queue() {
+        let endpoint = std::env::var("AWS_SQS_ENDPOINT_URL").unwrap_or_else(|_| "http://localhost:4566".to_string());
+        let config = AwsConfig {
+            region: "us-east-1".to_string(),
+            access_key_id: "test".to_string(),
+            secret_access_key: "test".to_string(),
+            session_token: String::new(),
+            sqs_endpoint_url: endpoint,
+            sns_endpoint_url: String::new(),
+            request_timeout_secs: 5,
+        };
+        let client = aws_sdk_sqs::Client::new(&load_sdk_config(AwsService::Sqs, &config).await);
+        let queue_url = client
+            .create_queue()
+            .queue_name(format!("tempus-test-{}.fifo", uuid::Uuid::new_v4().simple()))
+            .attributes(aws_sdk_sqs::types::QueueAttributeName::FifoQueue, "true")
+            .send()
+            .await
+            .unwrap()
+            .queue_url
+            .unwrap();
+
+        let mut job = job(&queue_url);
+        job.payload = serde_json::json!({"event": "order.created"});
+        job.options = serde_json::to_value(JobAwsMessageEntity::new(&queue_url, BTreeMap::new(), Some("customer-1".to_string()), None).unwrap()).unwrap();
+        let execution = JobExecutionEntity::start(&job, JobType::SQS, chrono::Utc::now());
+        let (_, result) = AwsJobExecutor::new(AwsService::Sqs, config).execute(&job, execution).await;
+        result.unwrap();
+
+        let received = client.receive_message().queue_url(&queue_url).send().await.unwrap();
+        let body = received.messages().first().and_then(|message| message.body()).unwrap();
+        assert!(body.contains("order.created"));
+
+        client.delete_queue().queue_url(&queue_url).send().await.unwrap();
+    }
+}
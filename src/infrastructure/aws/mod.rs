@@ -0,0 +1,2 @@
+pub mod aws_client;
+pub mod aws_job_executor;
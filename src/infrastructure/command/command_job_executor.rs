@@ -0,0 +1,339 @@
+use crate::config::app_config::CommandConfig;
+use crate::domain::job::entity::job_command_entity::JobCommandEntity;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::{truncate_body, JobExecutionEntity, RESPONSE_BODY_LIMIT};
+use crate::domain::job::port::driven::job_executor_port::{no_options, JobExecutor};
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::metrics::increment_commands;
+use log::debug;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::{ExitStatus, Stdio};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, Command};
+
+/// Runs `command` jobs as child processes of Tempus. The executable, env variables and working
+/// directory must be allow-listed, the environment is cleared, and a command still running at its
+/// timeout is killed.
+pub struct CommandJobExecutor {
+    config: CommandConfig,
+}
+
+struct CommandOutput {
+    status: ExitStatus,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+impl CommandJobExecutor {
+    pub fn new(config: CommandConfig) -> Self {
+        Self { config }
+    }
+
+    /// The job timeout, capped by the configured hard timeout.
+    fn timeout(&self, job: &JobEntity) -> Duration {
+        job.execution_timeout()
+            .map_or(self.config.timeout(), |timeout| timeout.min(self.config.timeout()))
+    }
+
+    /// Checks the executable, env variable names and working directory against the config.
+    fn check(&self, executable: &str, command: &JobCommandEntity) -> std::result::Result<(), String> {
+        if !self.config.is_allowed(executable) {
+            return Err(format!("Executable is not allowed: {}", executable));
+        }
+
+        if let Some(name) = command.env.keys().find(|name| !self.config.is_env_allowed(name)) {
+            return Err(format!("Env variable is not allowed: {}", name));
+        }
+
+        match &command.working_dir {
+            Some(working_dir) if !self.config.is_working_dir_allowed(Path::new(working_dir)) => {
+                Err(format!("Working directory is not allowed: {}", working_dir))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn run(&self, job: &JobEntity) -> Result<CommandOutput> {
+        let command = JobCommandEntity::from_payload(&job.payload)?;
+        self.check(&job.target, &command).map_err(TempusError::Command)?;
+
+        // Checked again once resolved, so a symlink cannot lead out of the allowed directories.
+        let working_dir = match &command.working_dir {
+            Some(working_dir) => {
+                let resolved = tokio::fs::canonicalize(working_dir)
+                    .await
+                    .map_err(|e| TempusError::Command(format!("Invalid working directory {}: {}", working_dir, e)))?;
+                if !self.config.is_working_dir_allowed(&resolved) {
+                    return Err(TempusError::Command(format!("Working directory is not allowed: {}", working_dir)));
+                }
+                Some(resolved)
+            }
+            None => None,
+        };
+
+        let mut child = build_command(&job.target, &command, working_dir.as_deref())
+            .spawn()
+            .map_err(|e| TempusError::Command(format!("Failed to start {}: {}", job.target, e)))?;
+        let mut process_group = ProcessGroup(child.id());
+
+        let timeout = self.timeout(job);
+        match tokio::time::timeout(timeout, wait_with_output(&mut child, command.stdin)).await {
+            Ok(output) => {
+                process_group.release();
+                output
+            }
+            Err(_) => {
+                process_group.kill();
+                child.kill().await?;
+                Err(TempusError::Timeout(timeout))
+            }
+        }
+    }
+}
+
+/// The group of a running command, which includes the processes it started. The whole group is
+/// killed when the command times out or the engine gives up on the job, so no grandchild outlives
+/// it; a command that finished is left alone.
+struct ProcessGroup(Option<u32>);
+
+impl ProcessGroup {
+    fn kill(&mut self) {
+        #[cfg(unix)]
+        if let Some(id) = self.0.take() {
+            // SAFETY: killpg only sends a signal; the group id is the pid of the command we spawned.
+            unsafe {
+                libc::killpg(id as libc::pid_t, libc::SIGKILL);
+            }
+        }
+    }
+
+    fn release(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+fn build_command(executable: &str, command: &JobCommandEntity, working_dir: Option<&Path>) -> Command {
+    let mut process = Command::new(executable);
+    process
+        .args(&command.args)
+        .env_clear()
+        .envs(&command.env)
+        .stdin(if command.stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // Also kills the command when the engine gives up on the job first.
+        .kill_on_drop(true);
+
+    // Leads a new process group, so the command and everything it starts can be killed together.
+    #[cfg(unix)]
+    process.process_group(0);
+
+    if let Some(working_dir) = working_dir {
+        process.current_dir(working_dir);
+    }
+
+    process
+}
+
+async fn wait_with_output(child: &mut Child, stdin: Option<String>) -> Result<CommandOutput> {
+    let stdin_pipe = child.stdin.take();
+    let stdout_pipe = child.stdout.take();
+    let stderr_pipe = child.stderr.take();
+
+    let write_stdin = async {
+        if let (Some(mut pipe), Some(stdin)) = (stdin_pipe, stdin) {
+            // Commands may exit without reading their input.
+            match pipe.write_all(stdin.as_bytes()).await {
+                Err(e) if e.kind() != ErrorKind::BrokenPipe => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(())
+    };
+
+    let (status, stdout, stderr, _) = tokio::try_join!(
+        child.wait(),
+        read_truncated(stdout_pipe),
+        read_truncated(stderr_pipe),
+        write_stdin
+    )?;
+
+    Ok(CommandOutput { status, stdout, stderr })
+}
+
+/// Keeps the first `RESPONSE_BODY_LIMIT` bytes and drains the rest, so chatty commands don't block
+/// on a full pipe.
+async fn read_truncated(pipe: Option<impl AsyncRead + Unpin>) -> std::io::Result<Vec<u8>> {
+    let mut output = Vec::new();
+    let Some(mut pipe) = pipe else {
+        return Ok(output);
+    };
+
+    let mut buffer = [0u8; 8192];
+    loop {
+        let read = pipe.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok(output);
+        }
+        let keep = read.min(RESPONSE_BODY_LIMIT.saturating_sub(output.len()));
+        output.extend_from_slice(&buffer[..keep]);
+    }
+}
+
+#[async_trait]
+impl JobExecutor for CommandJobExecutor {
+    /// Command jobs keep their arguments in the payload, so only the executable is checked here.
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        let command = JobCommandEntity::from_payload(&request.payload)?;
+        self.check(&request.target, &command).map_err(TempusError::Validation)?;
+        no_options(request)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        match self.run(job).await {
+            Ok(output) => {
+                debug!("Command {} for job {} finished with {}", job.target, job.id, output.status);
+                increment_commands(output.status.code());
+
+                let result = if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(TempusError::Command(format!("{} failed with {}", job.target, output.status)))
+                };
+                let execution = JobExecutionEntity {
+                    exit_code: output.status.code(),
+                    stdout: Some(truncate_body(&output.stdout)),
+                    stderr: Some(truncate_body(&output.stderr)),
+                    ..execution
+                };
+
+                (execution, result)
+            }
+            Err(e) => (execution, Err(e)),
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use sea_orm::JsonValue;
+    use serde_json::json;
+
+    const SH: &str = "/bin/sh";
+
+    fn job(payload: JsonValue, timeout: Option<chrono::Duration>) -> JobEntity {
+        JobEntity {
+            payload,
+            timeout,
+            ..JobEntity::fixture(JobType::COMMAND, SH)
+        }
+    }
+
+    fn executor(allowed_executables: &str) -> CommandJobExecutor {
+        CommandJobExecutor::new(CommandConfig {
+            allowed_executables: allowed_executables.to_string(),
+            allowed_env: "GREETING, LD_PRELOAD".to_string(),
+            working_dirs: "/tmp".to_string(),
+            timeout_secs: 5,
+        })
+    }
+
+    async fn execute(allowed_executables: &str, job: &JobEntity) -> (JobExecutionEntity, Result<()>) {
+        let executor = executor(allowed_executables);
+        let execution = JobExecutionEntity::start(job, JobType::COMMAND, chrono::Utc::now());
+
+        executor.execute(job, execution).await
+    }
+
+    #[tokio::test]
+    async fn test_execute_captures_output() {
+        let job = job(
+            json!({
+                "args": ["-c", "read line; echo \"$line $GREETING\"; pwd; echo oops >&2"],
+                "env": {"GREETING": "world"},
+                "working_dir": "/tmp",
+                "stdin": "hello\n"
+            }),
+            None,
+        );
+
+        let (execution, result) = execute(SH, &job).await;
+
+        result.unwrap();
+        assert_eq!(execution.exit_code, Some(0));
+        assert_eq!(execution.stdout.as_deref(), Some("hello world\n/tmp\n"));
+        assert_eq!(execution.stderr.as_deref(), Some("oops\n"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_fails_on_non_zero_exit() {
+        let job = job(json!({"args": ["-c", "echo failed >&2; exit 3"]}), None);
+
+        let (execution, result) = execute(SH, &job).await;
+
+        assert!(matches!(result, Err(TempusError::Command(_))));
+        assert_eq!(execution.exit_code, Some(3));
+        assert_eq!(execution.stderr.as_deref(), Some("failed\n"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_kills_command_on_timeout() {
+        let job = job(json!({"args": ["-c", "sleep 30"]}), Some(chrono::Duration::milliseconds(100)));
+
+        let started_at = std::time::Instant::now();
+        let (execution, result) = execute(SH, &job).await;
+
+        assert!(matches!(result, Err(TempusError::Timeout(_))));
+        assert!(started_at.elapsed() < Duration::from_secs(5));
+        assert_eq!(execution.exit_code, None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_kills_grandchildren_on_timeout() {
+        let marker = std::env::temp_dir().join(format!("tempus-command-{}", uuid::Uuid::new_v4()));
+        let script = format!("(sleep 1; touch {}) & wait", marker.display());
+        let job = job(json!({"args": ["-c", script]}), Some(chrono::Duration::milliseconds(200)));
+
+        let (_, result) = execute(SH, &job).await;
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+
+        assert!(matches!(result, Err(TempusError::Timeout(_))));
+        assert!(!marker.exists());
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_executables_outside_allow_list() {
+        let job = job(json!({"args": ["-c", "true"]}), None);
+
+        let (_, result) = execute("/usr/bin/env", &job).await;
+
+        assert!(matches!(result, Err(TempusError::Command(_))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_env_and_working_dir_outside_allow_list() {
+        for payload in [
+            json!({"env": {"PATH": "/tmp"}}),
+            json!({"env": {"LD_PRELOAD": "/tmp/hook.so"}}),
+            json!({"working_dir": "/etc"}),
+            json!({"working_dir": "/tmp/../etc"}),
+        ] {
+            let (_, result) = execute(SH, &job(payload, None)).await;
+
+            assert!(matches!(result, Err(TempusError::Command(_))));
+        }
+    }
+}
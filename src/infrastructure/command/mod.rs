@@ -0,0 +1 @@
+pub mod command_job_executor;
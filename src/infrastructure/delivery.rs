@@ -0,0 +1,43 @@
+use crate::domain::job::entity::job_entity::JobEntity;
+use std::collections::BTreeMap;
+
+pub const JOB_ID_HEADER: &str = "Tempus-Job-Id";
+pub const ATTEMPT_HEADER: &str = "Tempus-Attempt";
+pub const SCHEDULED_AT_HEADER: &str = "Tempus-Scheduled-At";
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Headers every delivery carries, whatever the transport. Executors insert them after the user
+/// headers, so they replace user headers of the same name.
+pub fn delivery_headers(job: &JobEntity) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (JOB_ID_HEADER.to_string(), job.id.to_string()),
+        (ATTEMPT_HEADER.to_string(), (job.retries + 1).to_string()),
+    ])
+}
+
+/// Stable across retries of an occurrence, so receivers and brokers can drop duplicates.
+pub fn idempotency_key(job: &JobEntity) -> String {
+    format!("{}:{}", job.id, job.occurrences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+
+    #[test]
+    fn test_delivery_headers() {
+        let job = JobEntity {
+            retries: 1,
+            occurrences: 3,
+            ..JobEntity::fixture(JobType::HTTP, "https://example.com")
+        };
+
+        let headers = delivery_headers(&job);
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get(JOB_ID_HEADER), Some(&job.id.to_string()));
+        assert_eq!(headers.get(ATTEMPT_HEADER).map(String::as_str), Some("2"));
+        assert_eq!(idempotency_key(&job), format!("{}:3", job.id));
+    }
+}
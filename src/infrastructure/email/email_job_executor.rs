@@ -0,0 +1,249 @@
+use crate::config::app_config::{SmtpConfig, SmtpTls};
+use crate::domain::job::entity::job_email_entity::{validate_email_address, JobEmailEntity};
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::port::driven::job_executor_port::{no_options, JobExecutor};
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::metrics::increment_emails;
+use lettre::message::header::ContentType;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::smtp::response::Response;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use log::debug;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+
+/// Sends `email` jobs from the address in their target, which must be an allowed sender, through
+/// the configured SMTP server.
+pub struct EmailJobExecutor {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    config: SmtpConfig,
+}
+
+impl EmailJobExecutor {
+    pub fn new(config: SmtpConfig) -> Result<Self> {
+        let builder = match config.tls {
+            SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host),
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host),
+            SmtpTls::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)),
+        }
+        .map_err(|e| TempusError::Config(format!("Invalid SMTP configuration: {}", e)))?
+        .port(config.port)
+        .timeout(Some(config.timeout()));
+
+        let builder = if config.username.is_empty() {
+            builder
+        } else {
+            builder.credentials(Credentials::new(config.username.clone(), config.password.clone()))
+        };
+
+        Ok(Self {
+            transport: builder.build(),
+            config,
+        })
+    }
+
+    async fn send(&self, job: &JobEntity) -> Result<Response> {
+        if !self.config.is_sender_allowed(&job.target) {
+            return Err(TempusError::Smtp {
+                message: format!("Sender is not allowed: {}", job.target),
+                retryable: false,
+            });
+        }
+
+        let message = build_message(&job.target, &JobEmailEntity::from_payload(&job.payload)?)?;
+        let timeout = job.execution_timeout().unwrap_or(self.config.timeout());
+
+        tokio::time::timeout(timeout, self.transport.send(message))
+            .await
+            .map_err(|_| TempusError::Timeout(timeout))?
+            .map_err(|e| TempusError::Smtp {
+                message: e.to_string(),
+                retryable: !e.is_permanent(),
+            })
+    }
+}
+
+/// Messages that cannot be built would fail the same way on every attempt, so the errors are
+/// terminal.
+fn build_message(from: &str, email: &JobEmailEntity) -> Result<Message> {
+    let invalid = |e: &dyn std::fmt::Display| TempusError::Smtp {
+        message: format!("Invalid email: {}", e),
+        retryable: false,
+    };
+    let mailbox = |address: &str| address.parse::<Mailbox>().map_err(|e| invalid(&e));
+    let rendered = email.render()?;
+
+    let mut builder = Message::builder().from(mailbox(from)?).subject(rendered.subject);
+    for to in &email.to {
+        builder = builder.to(mailbox(to)?);
+    }
+    for cc in &email.cc {
+        builder = builder.cc(mailbox(cc)?);
+    }
+
+    match (rendered.text, rendered.html) {
+        (Some(text), Some(html)) => builder.multipart(MultiPart::alternative_plain_html(text, html)),
+        (Some(text), None) => builder.header(ContentType::TEXT_PLAIN).body(text),
+        (None, Some(html)) => builder.header(ContentType::TEXT_HTML).body(html),
+        (None, None) => return Err(TempusError::Validation("Emails need a text or html body".to_string())),
+    }
+    .map_err(|e| invalid(&e))
+}
+
+#[async_trait]
+impl JobExecutor for EmailJobExecutor {
+    /// Email jobs keep their message in the payload and are sent from the target address.
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        validate_email_address(&request.target)?;
+        if !self.config.is_sender_allowed(&request.target) {
+            return Err(TempusError::Validation(format!("Sender is not allowed: {}", request.target)));
+        }
+
+        JobEmailEntity::from_payload(&request.payload)?;
+        no_options(request)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let result = self.send(job).await.map(|response| {
+            debug!("Email for job {} accepted with {}", job.id, response.code());
+            increment_emails();
+        });
+
+        (execution, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use serde_json::json;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    fn job() -> JobEntity {
+        JobEntity {
+            payload: json!({
+                "to": ["ada@example.com"],
+                "cc": ["charles@example.com"],
+                "subject": "Reminder for {{name}}",
+                "text": "Hi {{name}}",
+                "html": "<p>Hi {{name}}</p>",
+                "variables": {"name": "Ada"}
+            }),
+            ..JobEntity::fixture(JobType::EMAIL, "reminders@example.com")
+        }
+    }
+
+    /// A one-connection SMTP sink that answers `RCPT TO` with `rcpt_reply` and returns the data
+    /// it received.
+    async fn smtp_sink(rcpt_reply: &'static str) -> (u16, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let sink = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            let mut data = String::new();
+            let mut in_data = false;
+
+            writer.write_all(b"220 sink ready\r\n").await.unwrap();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let reply = if in_data {
+                    if line == "." {
+                        in_data = false;
+                        "250 queued"
+                    } else {
+                        data.push_str(&line);
+                        data.push('\n');
+                        continue;
+                    }
+                } else {
+                    match line.split_whitespace().next().unwrap_or_default().to_uppercase().as_str() {
+                        "EHLO" | "HELO" | "MAIL" | "RSET" | "NOOP" => "250 ok",
+                        "RCPT" => rcpt_reply,
+                        "DATA" => {
+                            in_data = true;
+                            "354 go ahead"
+                        }
+                        "QUIT" => {
+                            writer.write_all(b"221 bye\r\n").await.unwrap();
+                            break;
+                        }
+                        _ => "502 not implemented",
+                    }
+                };
+                writer.write_all(format!("{}\r\n", reply).as_bytes()).await.unwrap();
+            }
+
+            data
+        });
+
+        (port, sink)
+    }
+
+    fn executor(port: u16) -> EmailJobExecutor {
+        EmailJobExecutor::new(SmtpConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            tls: SmtpTls::None,
+            username: String::new(),
+            password: String::new(),
+            from: "noreply@example.com".to_string(),
+            allowed_senders: "reminders@example.com".to_string(),
+            timeout_secs: 5,
+        })
+        .unwrap()
+    }
+
+    async fn execute(port: u16, job: &JobEntity) -> Result<()> {
+        let execution = JobExecutionEntity::start(job, JobType::EMAIL, chrono::Utc::now());
+        let (_, result) = executor(port).execute(job, execution).await;
+        result
+    }
+
+    #[tokio::test]
+    async fn test_execute_sends_rendered_email() {
+        let (port, sink) = smtp_sink("250 ok").await;
+
+        execute(port, &job()).await.unwrap();
+
+        let data = sink.await.unwrap();
+        assert!(data.contains("From: reminders@example.com"));
+        assert!(data.contains("To: ada@example.com"));
+        assert!(data.contains("Cc: charles@example.com"));
+        assert!(data.contains("Subject: Reminder for Ada"));
+        assert!(data.contains("<p>Hi Ada</p>"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_classifies_smtp_replies() {
+        let (port, _) = smtp_sink("550 mailbox unavailable").await;
+        assert!(matches!(execute(port, &job()).await, Err(TempusError::Smtp { retryable: false, .. })));
+
+        let (port, _) = smtp_sink("451 try again later").await;
+        assert!(matches!(execute(port, &job()).await, Err(TempusError::Smtp { retryable: true, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_senders_outside_allow_list() {
+        let job = JobEntity {
+            target: "ceo@example.com".to_string(),
+            ..job()
+        };
+
+        assert!(matches!(execute(25, &job).await, Err(TempusError::Smtp { retryable: false, .. })));
+    }
+
+    #[test]
+    fn test_build_message_rejects_invalid_sender() {
+        let email = JobEmailEntity::from_payload(&job().payload).unwrap();
+
+        assert!(matches!(build_message("not-an-address", &email), Err(TempusError::Smtp { retryable: false, .. })));
+        assert!(build_message("reminders@example.com", &email).is_ok());
+    }
+}
@@ -0,0 +1,473 @@
+use crate::config::app_config::GrpcConfig;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::{truncate_body, JobExecutionEntity};
+use crate::domain::job::entity::job_grpc_request_entity::JobGrpcRequestEntity;
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::grpc::dynamic_codec::DynamicCodec;
+use crate::infrastructure::delivery::{delivery_headers, idempotency_key, IDEMPOTENCY_KEY_HEADER};
+use crate::infrastructure::metrics::increment_grpc_requests;
+use prost_reflect::{DescriptorPool, DynamicMessage, MethodDescriptor};
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::{Code, Status};
+
+/// Calls unary methods for `grpc` jobs on the endpoint named by their target. Payloads are
+/// converted from JSON with the configured descriptor set, and a non-OK status fails the execution
+/// with `TempusError::GrpcStatus`.
+pub struct GrpcJobExecutor {
+    config: GrpcConfig,
+    descriptors: DescriptorPool,
+    channels: Mutex<HashMap<String, Channel>>,
+}
+
+impl GrpcJobExecutor {
+    pub fn new(config: GrpcConfig) -> Result<Self> {
+        let descriptors = if config.descriptor_set_path.is_empty() {
+            DescriptorPool::new()
+        } else {
+            let descriptor_set = std::fs::read(&config.descriptor_set_path).map_err(|e| {
+                TempusError::Config(format!("Failed to read {}: {}", config.descriptor_set_path, e))
+            })?;
+            DescriptorPool::decode(descriptor_set.as_slice()).map_err(|e| {
+                TempusError::Config(format!("Invalid descriptor set {}: {}", config.descriptor_set_path, e))
+            })?
+        };
+
+        Ok(Self {
+            config,
+            descriptors,
+            channels: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Channels connect on first use and are shared by every job calling the same endpoint.
+    async fn channel(&self, endpoint: &str) -> Result<Channel> {
+        let mut channels = self.channels.lock().await;
+        if let Some(channel) = channels.get(endpoint) {
+            return Ok(channel.clone());
+        }
+
+        let mut channel_endpoint = Endpoint::from_shared(endpoint.to_string())
+            .map_err(|e| TempusError::Grpc(format!("Invalid endpoint {}: {}", endpoint, e)))?
+            .connect_timeout(self.config.connect_timeout());
+        if endpoint.starts_with("https://") {
+            channel_endpoint = channel_endpoint
+                .tls_config(ClientTlsConfig::new().with_native_roots())
+                .map_err(|e| TempusError::Grpc(format!("Invalid TLS settings for {}: {}", endpoint, e)))?;
+        }
+
+        let channel = channel_endpoint.connect_lazy();
+        channels.insert(endpoint.to_string(), channel.clone());
+        Ok(channel)
+    }
+
+    fn method(&self, grpc_request: &JobGrpcRequestEntity) -> Result<MethodDescriptor> {
+        let (service, method) = grpc_request.service_and_method();
+
+        self.descriptors
+            .get_service_by_name(service)
+            .and_then(|service| service.methods().find(|candidate| candidate.name() == method))
+            .filter(|method| !method.is_client_streaming() && !method.is_server_streaming())
+            .ok_or_else(|| {
+                TempusError::Grpc(format!("Unary method not found in descriptor set: {}", grpc_request.method))
+            })
+    }
+
+    /// Resolves the method and converts the payload like a call would, without calling.
+    fn check(&self, grpc_request: &JobGrpcRequestEntity, payload: &JsonValue) -> Result<()> {
+        let method = self.method(grpc_request)?;
+        to_message(&method, payload).map(|_| ())
+    }
+
+    async fn call(
+        &self,
+        job: &JobEntity,
+        grpc_request: &JobGrpcRequestEntity,
+        timeout: Duration,
+    ) -> Result<std::result::Result<DynamicMessage, Status>> {
+        let method = self.method(grpc_request)?;
+        let message = to_message(&method, &job.payload)?;
+
+        let mut request = tonic::Request::new(message);
+        request.set_timeout(timeout);
+        *request.metadata_mut() = to_metadata(&with_grpc_delivery_metadata(job, grpc_request))?;
+
+        let path = PathAndQuery::try_from(format!("/{}", grpc_request.method))
+            .map_err(|e| TempusError::Grpc(format!("Invalid method {}: {}", grpc_request.method, e)))?;
+        let mut client = tonic::client::Grpc::new(self.channel(&job.target).await?);
+        if let Err(e) = client.ready().await {
+            return Ok(Err(Status::unavailable(e.to_string())));
+        }
+
+        Ok(client
+            .unary(request, path, DynamicCodec::new(method.output()))
+            .await
+            .map(tonic::Response::into_inner))
+    }
+}
+
+fn to_message(method: &MethodDescriptor, payload: &JsonValue) -> Result<DynamicMessage> {
+    if payload.is_null() {
+        return Ok(DynamicMessage::new(method.input()));
+    }
+
+    DynamicMessage::deserialize(method.input(), payload.clone()).map_err(|e| {
+        TempusError::Grpc(format!("Payload is not a valid {}: {}", method.input().full_name(), e))
+    })
+}
+
+/// Metadata keys are lowercase on the wire.
+fn with_grpc_delivery_metadata(job: &JobEntity, grpc_request: &JobGrpcRequestEntity) -> BTreeMap<String, String> {
+    let mut metadata = grpc_request.metadata.clone();
+    metadata.extend(delivery_headers(job).into_iter().map(|(name, value)| (name.to_ascii_lowercase(), value)));
+    metadata.insert(IDEMPOTENCY_KEY_HEADER.to_ascii_lowercase(), idempotency_key(job));
+    metadata
+}
+
+fn to_metadata(metadata: &BTreeMap<String, String>) -> Result<MetadataMap> {
+    let mut metadata_map = MetadataMap::new();
+
+    for (name, value) in metadata {
+        let key = MetadataKey::from_bytes(name.as_bytes())
+            .map_err(|e| TempusError::Grpc(format!("Invalid metadata key {}: {}", name, e)))?;
+        let value = MetadataValue::try_from(value.as_str())
+            .map_err(|e| TempusError::Grpc(format!("Invalid value for metadata key {}: {}", name, e)))?;
+        metadata_map.insert(key, value);
+    }
+
+    Ok(metadata_map)
+}
+
+/// Statuses a later attempt may not get, such as `UNAVAILABLE` or `RESOURCE_EXHAUSTED`, are
+/// retryable like 5xx and 429 responses to HTTP jobs, unless the server pushes back on retries;
+/// the rest mean the call itself is wrong.
+fn classify_status(status: &Status) -> TempusError {
+    let pushback = parse_retry_pushback(status.metadata());
+    let retryable = matches!(
+        status.code(),
+        Code::Cancelled
+            | Code::Unknown
+            | Code::DeadlineExceeded
+            | Code::ResourceExhausted
+            | Code::Aborted
+            | Code::Internal
+            | Code::Unavailable
+    ) && pushback != Some(None);
+
+    TempusError::GrpcStatus {
+        code: status.code().into(),
+        message: status.message().to_string(),
+        retryable,
+        retry_after: pushback.flatten().filter(|_| retryable),
+    }
+}
+
+/// `None` without a pushback, and `Some(None)` when the server asks not to be retried, which it
+/// does with a negative or unparseable value.
+fn parse_retry_pushback(metadata: &MetadataMap) -> Option<Option<Duration>> {
+    let pushback = metadata.get("grpc-retry-pushback-ms")?;
+    Some(
+        pushback
+            .to_str()
+            .ok()
+            .and_then(|pushback| pushback.trim().parse::<u64>().ok())
+            .map(Duration::from_millis),
+    )
+}
+
+#[async_trait]
+impl JobExecutor for GrpcJobExecutor {
+    /// The method must be in the descriptor set and the payload must convert to its request.
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        let grpc_request = JobGrpcRequestEntity::from_options(&request.options, &request.target)?;
+        self.check(&grpc_request, &request.payload).map_err(|e| match e {
+            TempusError::Grpc(message) => TempusError::Validation(message),
+            e => e,
+        })?;
+
+        Ok(serde_json::to_value(grpc_request)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let grpc_request = match job.options::<JobGrpcRequestEntity>() {
+            Ok(Some(grpc_request)) => grpc_request,
+            Ok(None) => return (execution, Err(TempusError::Grpc(format!("Job {} has no gRPC request", job.id)))),
+            Err(e) => return (execution, Err(e)),
+        };
+        let timeout = job.execution_timeout().unwrap_or(self.config.request_timeout());
+
+        let response = match tokio::time::timeout(timeout, self.call(job, &grpc_request, timeout)).await {
+            Ok(response) => response,
+            Err(_) => return (execution, Err(TempusError::Timeout(timeout))),
+        };
+
+        match response {
+            Ok(Ok(message)) => {
+                increment_grpc_requests(Code::Ok.into());
+                let body = serde_json::to_vec(&message).unwrap_or_default();
+                let execution = JobExecutionEntity {
+                    status_code: Some(Code::Ok.into()),
+                    response_body: Some(truncate_body(&body)),
+                    ..execution
+                };
+                (execution, Ok(()))
+            }
+            Ok(Err(status)) => {
+                increment_grpc_requests(status.code().into());
+                let execution = JobExecutionEntity {
+                    status_code: Some(status.code().into()),
+                    ..execution
+                };
+                (execution, Err(classify_status(&status)))
+            }
+            Err(e) => (execution, Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use prost::Message;
+    use prost_reflect::prost_types::field_descriptor_proto::{Label, Type};
+    use prost_reflect::prost_types::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet, MethodDescriptorProto,
+        ServiceDescriptorProto,
+    };
+    use prost_reflect::{MessageDescriptor, Value};
+    use serde_json::json;
+    use std::convert::Infallible;
+    use tonic::body::Body;
+    use tonic::codegen::{http, BoxFuture, Context, Poll, Service};
+    use tonic::server::NamedService;
+
+    fn descriptor_set() -> FileDescriptorSet {
+        let field = |name: &str, number: i32, r#type: Type| FieldDescriptorProto {
+            name: Some(name.to_string()),
+            json_name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(r#type as i32),
+            ..Default::default()
+        };
+        let method = |name: &str| MethodDescriptorProto {
+            name: Some(name.to_string()),
+            input_type: Some(".tempus.test.Ping".to_string()),
+            output_type: Some(".tempus.test.Ping".to_string()),
+            ..Default::default()
+        };
+
+        FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("echo.proto".to_string()),
+                package: Some("tempus.test".to_string()),
+                syntax: Some("proto3".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("Ping".to_string()),
+                    field: vec![field("text", 1, Type::String), field("attempt", 2, Type::Int64)],
+                    ..Default::default()
+                }],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("Echo".to_string()),
+                    method: vec![method("Echo")],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
+    /// Answers `tempus.test.Echo/Echo` with the text and the `x-tenant` metadata it was called
+    /// with, or with the status the text names.
+    #[derive(Clone)]
+    struct EchoServer {
+        ping: MessageDescriptor,
+    }
+
+    impl NamedService for EchoServer {
+        const NAME: &'static str = "tempus.test.Echo";
+    }
+
+    impl Service<http::Request<Body>> for EchoServer {
+        type Response = http::Response<Body>;
+        type Error = Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+            let mut grpc = tonic::server::Grpc::new(DynamicCodec::new(self.ping.clone()));
+            Box::pin(async move { Ok(grpc.unary(Echo, request).await) })
+        }
+    }
+
+    struct Echo;
+
+    impl Service<tonic::Request<DynamicMessage>> for Echo {
+        type Response = tonic::Response<DynamicMessage>;
+        type Error = Status;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: tonic::Request<DynamicMessage>) -> Self::Future {
+            let metadata = |name: &str| {
+                request.metadata().get(name).and_then(|value| value.to_str().ok()).unwrap_or_default().to_string()
+            };
+            let tenant = metadata("x-tenant");
+            let attempt = metadata("tempus-attempt").parse::<i64>().unwrap_or_default();
+            let mut message = request.into_inner();
+            let text = message.get_field_by_name("text").and_then(|text| text.as_str().map(str::to_string));
+
+            Box::pin(async move {
+                match text.as_deref() {
+                    Some("unavailable") => {
+                        let mut status = Status::unavailable("shutting down");
+                        status.metadata_mut().insert("grpc-retry-pushback-ms", MetadataValue::from_static("1500"));
+                        Err(status)
+                    }
+                    Some("invalid") => Err(Status::invalid_argument("bad ping")),
+                    Some("draining") => {
+                        let mut status = Status::unavailable("draining");
+                        status.metadata_mut().insert("grpc-retry-pushback-ms", MetadataValue::from_static("-1"));
+                        Err(status)
+                    }
+                    text => {
+                        message.set_field_by_name("text", Value::String(format!("{} from {}", text.unwrap_or_default(), tenant)));
+                        message.set_field_by_name("attempt", Value::I64(attempt));
+                        Ok(tonic::Response::new(message))
+                    }
+                }
+            })
+        }
+    }
+
+    async fn start_echo_server() -> String {
+        let pool = DescriptorPool::from_file_descriptor_set(descriptor_set()).unwrap();
+        let ping = pool.get_message_by_name("tempus.test.Ping").unwrap();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(EchoServer { ping })
+                .serve_with_incoming(tonic::codegen::tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+
+        format!("http://{}", address)
+    }
+
+    fn executor() -> GrpcJobExecutor {
+        let descriptor_set_path = std::env::temp_dir().join(format!("tempus-grpc-{}.pb", uuid::Uuid::new_v4()));
+        std::fs::write(&descriptor_set_path, descriptor_set().encode_to_vec()).unwrap();
+
+        let executor = GrpcJobExecutor::new(GrpcConfig {
+            descriptor_set_path: descriptor_set_path.to_string_lossy().to_string(),
+            connect_timeout_secs: 5,
+            request_timeout_secs: 5,
+        })
+        .unwrap();
+        std::fs::remove_file(descriptor_set_path).unwrap();
+        executor
+    }
+
+    fn job(endpoint: &str, method: &str, payload: JsonValue) -> JobEntity {
+        let metadata = BTreeMap::from([("x-tenant".to_string(), "acme".to_string())]);
+
+        JobEntity {
+            retries: 1,
+            payload,
+            options: serde_json::to_value(JobGrpcRequestEntity::new(endpoint, Some(method), metadata).unwrap()).unwrap(),
+            ..JobEntity::fixture(JobType::GRPC, endpoint)
+        }
+    }
+
+    async fn execute(executor: &GrpcJobExecutor, job: &JobEntity) -> (JobExecutionEntity, Result<()>) {
+        let execution = JobExecutionEntity::start(job, JobType::GRPC, chrono::Utc::now());
+        executor.execute(job, execution).await
+    }
+
+    #[tokio::test]
+    async fn test_execute_calls_unary_method() {
+        let endpoint = start_echo_server().await;
+        let executor = executor();
+
+        let (execution, result) = execute(&executor, &job(&endpoint, "tempus.test.Echo/Echo", json!({"text": "hello"}))).await;
+
+        result.unwrap();
+        assert_eq!(execution.status_code, Some(0));
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(execution.response_body.as_deref().unwrap()).unwrap(),
+            json!({"text": "hello from acme", "attempt": "2"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_classifies_statuses() {
+        let endpoint = start_echo_server().await;
+        let executor = executor();
+
+        let (execution, result) = execute(&executor, &job(&endpoint, "tempus.test.Echo/Echo", json!({"text": "unavailable"}))).await;
+        assert_eq!(execution.status_code, Some(Code::Unavailable.into()));
+        assert!(matches!(
+            result,
+            Err(TempusError::GrpcStatus { code: 14, retryable: true, retry_after: Some(retry_after), .. })
+                if retry_after == Duration::from_millis(1500)
+        ));
+
+        let (_, result) = execute(&executor, &job(&endpoint, "tempus.test.Echo/Echo", json!({"text": "invalid"}))).await;
+        assert!(matches!(result, Err(TempusError::GrpcStatus { code: 3, retryable: false, retry_after: None, .. })));
+
+        let (_, result) = execute(&executor, &job(&endpoint, "tempus.test.Echo/Echo", json!({"text": "draining"}))).await;
+        assert!(matches!(result, Err(TempusError::GrpcStatus { code: 14, retryable: false, retry_after: None, .. })));
+    }
+
+    #[test]
+    fn test_parse_retry_pushback() {
+        let metadata = |pushback: &'static str| {
+            let mut metadata = MetadataMap::new();
+            metadata.insert("grpc-retry-pushback-ms", MetadataValue::from_static(pushback));
+            metadata
+        };
+
+        assert_eq!(parse_retry_pushback(&MetadataMap::new()), None);
+        assert_eq!(parse_retry_pushback(&metadata("250")), Some(Some(Duration::from_millis(250))));
+        assert_eq!(parse_retry_pushback(&metadata("-1")), Some(None));
+        assert_eq!(parse_retry_pushback(&metadata("soon")), Some(None));
+    }
+
+    #[test]
+    fn test_check_resolves_method_and_payload() {
+        let executor = executor();
+        let grpc_request = |method: &str| JobGrpcRequestEntity::new("http://127.0.0.1:1", Some(method), BTreeMap::new()).unwrap();
+
+        assert!(executor.check(&grpc_request("tempus.test.Echo/Echo"), &json!({"text": "hello"})).is_ok());
+        assert!(executor.check(&grpc_request("tempus.test.Echo/Shout"), &JsonValue::Null).is_err());
+        assert!(executor.check(&grpc_request("tempus.test.Echo/Echo"), &json!({"volume": 11})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_unknown_methods_and_payloads() {
+        let executor = executor();
+        let endpoint = "http://127.0.0.1:1";
+
+        let (_, result) = execute(&executor, &job(endpoint, "tempus.test.Echo/Shout", JsonValue::Null)).await;
+        assert!(matches!(result, Err(TempusError::Grpc(_))));
+
+        let (_, result) = execute(&executor, &job(endpoint, "tempus.test.Echo/Echo", json!({"volume": 11}))).await;
+        assert!(matches!(result, Err(TempusError::Grpc(_))));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod dynamic_codec;
+pub mod grpc_job_executor;
@@ -0,0 +1,454 @@
+use crate::config::app_config::HttpConfig;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::{
+    truncate_body, truncate_headers, JobExecutionEntity, RESPONSE_BODY_LIMIT,
+};
+use crate::domain::job::entity::job_http_request_entity::{HttpBodyEncoding, HttpMethod, JobHttpRequestEntity};
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::delivery::{delivery_headers, idempotency_key, IDEMPOTENCY_KEY_HEADER, SCHEDULED_AT_HEADER};
+use crate::infrastructure::http::webhook_signer::{sign, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+use crate::infrastructure::metrics::increment_http_requests;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Client, Method, Request, RequestBuilder, Response, StatusCode};
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// Delivers `http` jobs as webhook requests. A response outside the job's success statuses fails
+/// the execution with `TempusError::HttpStatus`.
+pub struct HttpJobExecutor {
+    client: Client,
+    config: HttpConfig,
+}
+
+impl HttpJobExecutor {
+    pub fn new(config: HttpConfig) -> Result<Self> {
+        let client = Client::builder()
+            .pool_idle_timeout(config.pool_idle_timeout())
+            .timeout(config.request_timeout())
+            .build()
+            .map_err(TempusError::Http)?;
+
+        Ok(Self { client, config })
+    }
+}
+
+#[async_trait]
+impl JobExecutor for HttpJobExecutor {
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        let http_request = JobHttpRequestEntity::from_options(&request.options, &request.payload)?;
+        if let Some(signing_key) = &http_request.signing_key {
+            self.config
+                .signing_key(signing_key)
+                .ok_or_else(|| TempusError::Validation(format!("Unknown signing key: {}", signing_key)))?;
+        }
+        Ok(serde_json::to_value(http_request)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let http_request = match job.options::<JobHttpRequestEntity>() {
+            Ok(http_request) => http_request.unwrap_or_default(),
+            Err(e) => return (execution, Err(e)),
+        };
+
+        let response = async {
+            let response = perform_request(&self.client, job, &http_request, &self.config).await?;
+            read_response(response).await
+        }
+        .await;
+
+        match response {
+            Ok(response) => {
+                increment_http_requests(response.status.as_u16());
+                let result = classify_response(response.status, &response.headers, &http_request, Utc::now());
+                let execution = JobExecutionEntity {
+                    status_code: Some(response.status.as_u16() as i32),
+                    response_headers: Some(to_recorded_headers(&response.headers)),
+                    response_body: Some(response.body),
+                    ..execution
+                };
+                (execution, result)
+            }
+            Err(e) => (execution, Err(e)),
+        }
+    }
+}
+
+/// The response as recorded on the execution; the body is cut to `RESPONSE_BODY_LIMIT` bytes.
+struct HttpResponseRecord {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: String,
+}
+
+async fn read_response(mut response: Response) -> Result<HttpResponseRecord> {
+    let status = response.status();
+    let headers = response.headers().clone();
+    let mut body = Vec::new();
+
+    // A body that fails midway is still recorded as far as it was read.
+    while body.len() < RESPONSE_BODY_LIMIT {
+        let Ok(Some(chunk)) = response.chunk().await else {
+            break;
+        };
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(HttpResponseRecord {
+        status,
+        headers,
+        body: truncate_body(&body),
+    })
+}
+
+fn to_recorded_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    let headers = headers.iter().fold(BTreeMap::new(), |mut recorded: BTreeMap<String, String>, (name, value)| {
+        let value = String::from_utf8_lossy(value.as_bytes());
+        recorded
+            .entry(name.to_string())
+            .and_modify(|recorded_value| {
+                recorded_value.push_str(", ");
+                recorded_value.push_str(&value);
+            })
+            .or_insert_with(|| value.to_string());
+        recorded
+    });
+
+    truncate_headers(headers)
+}
+
+fn validate_url(url: &str) -> Result<()> {
+    if url.is_empty() {
+        return Err(TempusError::Validation("URL cannot be empty".to_string()));
+    }
+    
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(TempusError::Validation("URL must start with http:// or https://".to_string()));
+    }
+    
+    Ok(())
+}
+
+async fn perform_request(
+    client: &Client,
+    job: &JobEntity,
+    http_request: &JobHttpRequestEntity,
+    http_config: &HttpConfig,
+) -> Result<Response> {
+    validate_url(&job.target)?;
+    
+    let mut request = build_request(client, job.target.clone(), job.payload.clone(), http_request)
+        .build()
+        .map_err(TempusError::Http)?;
+    let timeout = job.execution_timeout().unwrap_or(http_config.request_timeout());
+    *request.timeout_mut() = Some(timeout);
+    let signing_secrets = match &http_request.signing_key {
+        Some(signing_key) => http_config
+            .signing_key(signing_key)
+            .ok_or_else(|| TempusError::JobProcessing(format!("Unknown signing key: {}", signing_key)))?,
+        None => http_config.signing_secrets_for(request.url().host_str().unwrap_or_default()),
+    };
+    add_delivery_headers(&mut request, job, &signing_secrets, Utc::now())?;
+
+    client
+        .execute(request)
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                TempusError::Timeout(timeout)
+            } else {
+                TempusError::Http(e)
+            }
+        })
+}
+
+fn add_delivery_headers(
+    request: &mut Request,
+    job: &JobEntity,
+    signing_secrets: &[String],
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let timestamp = now.timestamp();
+    let signature = sign(
+        signing_secrets,
+        timestamp,
+        request.body().and_then(|body| body.as_bytes()).unwrap_or_default(),
+    );
+
+    let mut headers = delivery_headers(job);
+    headers.insert(SCHEDULED_AT_HEADER.to_string(), job.scheduled_at.to_rfc3339());
+    headers.insert(IDEMPOTENCY_KEY_HEADER.to_string(), idempotency_key(job));
+    headers.insert(TIMESTAMP_HEADER.to_string(), timestamp.to_string());
+    if let Some(signature) = signature {
+        headers.insert(SIGNATURE_HEADER.to_string(), signature);
+    }
+
+    for (name, value) in headers {
+        let name = HeaderName::try_from(name.as_str())
+            .map_err(|e| TempusError::JobProcessing(format!("Invalid {} header: {}", name, e)))?;
+        let value = HeaderValue::try_from(value)
+            .map_err(|e| TempusError::JobProcessing(format!("Invalid {} header: {}", name, e)))?;
+        request.headers_mut().insert(name, value);
+    }
+
+    Ok(())
+}
+
+/// 5xx and 429 responses are retryable and may carry a `Retry-After`; any other status outside
+/// the success set is terminal.
+fn classify_response(
+    status: StatusCode,
+    headers: &HeaderMap,
+    http_request: &JobHttpRequestEntity,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    if http_request.is_success(status.as_u16()) {
+        return Ok(());
+    }
+
+    let retryable = status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+
+    Err(TempusError::HttpStatus {
+        status: status.as_u16(),
+        retryable,
+        retry_after: retryable.then(|| parse_retry_after(headers, now)).flatten(),
+    })
+}
+
+/// `Retry-After` is either a number of seconds or an HTTP date.
+fn parse_retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<std::time::Duration> {
+    let retry_after = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    retry_after
+        .parse::<u64>()
+        .ok()
+        .map(std::time::Duration::from_secs)
+        .or_else(|| {
+            DateTime::parse_from_rfc2822(retry_after)
+                .ok()
+                .map(|date| (date.with_timezone(&Utc) - now).to_std().unwrap_or_default())
+        })
+}
+
+fn to_reqwest_method(method: HttpMethod) -> Method {
+    match method {
+        HttpMethod::Get => Method::GET,
+        HttpMethod::Post => Method::POST,
+        HttpMethod::Put => Method::PUT,
+        HttpMethod::Patch => Method::PATCH,
+        HttpMethod::Delete => Method::DELETE,
+        HttpMethod::Head => Method::HEAD,
+        HttpMethod::Options => Method::OPTIONS,
+    }
+}
+
+fn build_request(
+    client: &Client,
+    target: String,
+    payload: JsonValue,
+    http_request: &JobHttpRequestEntity,
+) -> RequestBuilder {
+    let request = http_request.headers.iter().fold(
+        client
+            .request(to_reqwest_method(http_request.method), target)
+            .query(&http_request.query),
+        |request, (name, value)| request.header(name, value),
+    );
+
+    match http_request.body_encoding {
+        HttpBodyEncoding::Json => request.json(&payload),
+        HttpBodyEncoding::Form => request.form(&payload),
+        HttpBodyEncoding::Text => {
+            let has_content_type = http_request
+                .headers
+                .keys()
+                .any(|name| name.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+            let request = if has_content_type {
+                request
+            } else {
+                request.header(CONTENT_TYPE, "text/plain; charset=utf-8")
+            };
+            request.body(payload.as_str().unwrap_or_default().to_string())
+        }
+        HttpBodyEncoding::None => request,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use crate::infrastructure::delivery::{ATTEMPT_HEADER, JOB_ID_HEADER};
+
+    fn job() -> JobEntity {
+        JobEntity::fixture(JobType::HTTP, "https://example.com")
+    }
+
+    #[test]
+    fn test_classify_response() {
+        let now = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let classify = |status: u16, headers: &HeaderMap| {
+            classify_response(StatusCode::from_u16(status).unwrap(), headers, &JobHttpRequestEntity::default(), now)
+        };
+        let no_headers = HeaderMap::new();
+
+        assert!(classify(200, &no_headers).is_ok());
+        assert!(matches!(
+            classify(404, &no_headers),
+            Err(TempusError::HttpStatus { status: 404, retryable: false, retry_after: None })
+        ));
+        assert!(matches!(
+            classify(503, &no_headers),
+            Err(TempusError::HttpStatus { status: 503, retryable: true, retry_after: None })
+        ));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "120".parse().unwrap());
+        assert!(matches!(
+            classify(429, &headers),
+            Err(TempusError::HttpStatus { retryable: true, retry_after: Some(retry_after), .. })
+                if retry_after == std::time::Duration::from_secs(120)
+        ));
+        assert!(matches!(
+            classify(400, &headers),
+            Err(TempusError::HttpStatus { retryable: false, retry_after: None, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let now = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut headers = HeaderMap::new();
+
+        headers.insert(RETRY_AFTER, (now + chrono::Duration::seconds(90)).to_rfc2822().parse().unwrap());
+        assert_eq!(parse_retry_after(&headers, now), Some(std::time::Duration::from_secs(90)));
+
+        headers.insert(RETRY_AFTER, (now - chrono::Duration::seconds(90)).to_rfc2822().parse().unwrap());
+        assert_eq!(parse_retry_after(&headers, now), Some(std::time::Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers, now), None);
+    }
+
+    #[test]
+    fn test_to_recorded_headers_joins_repeated_headers() {
+        let mut headers = HeaderMap::new();
+        headers.append("set-cookie", "a=1".parse().unwrap());
+        headers.append("set-cookie", "b=2".parse().unwrap());
+        headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+
+        let recorded = to_recorded_headers(&headers);
+
+        assert_eq!(recorded.get("set-cookie").map(String::as_str), Some("a=1, b=2"));
+        assert_eq!(recorded.get("content-type").map(String::as_str), Some("application/json"));
+    }
+
+    fn http_request(method: &str, body_encoding: &str) -> JobHttpRequestEntity {
+        JobHttpRequestEntity::new(
+            Some(method),
+            std::collections::BTreeMap::from([("X-Api-Key".to_string(), "secret".to_string())]),
+            std::collections::BTreeMap::from([("page".to_string(), "2".to_string())]),
+            Some(body_encoding),
+            &[],
+            None,
+            &serde_json::json!({"name": "tempus", "count": 1, "active": true}),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_request_applies_method_headers_and_query() {
+        let request = build_request(
+            &Client::new(),
+            "https://example.com/hook".to_string(),
+            serde_json::json!({"name": "tempus"}),
+            &http_request("PUT", "json"),
+        )
+        .build()
+        .unwrap();
+
+        assert_eq!(request.method(), Method::PUT);
+        assert_eq!(request.url().as_str(), "https://example.com/hook?page=2");
+        assert_eq!(request.headers()["x-api-key"], "secret");
+        assert_eq!(request.headers()[CONTENT_TYPE], "application/json");
+    }
+
+    #[test]
+    fn test_build_request_encodes_body() {
+        let build = |body_encoding: &str, payload: JsonValue| {
+            let mut http_request = http_request("POST", "json");
+            http_request.body_encoding = body_encoding.parse().unwrap();
+            build_request(&Client::new(), "https://example.com".to_string(), payload, &http_request)
+                .build()
+                .unwrap()
+        };
+        let body = |request: &reqwest::Request| {
+            request.body().and_then(|body| body.as_bytes()).map(|bytes| String::from_utf8_lossy(bytes).to_string())
+        };
+
+        let form = build("form", serde_json::json!({"name": "tempus", "count": 1}));
+        assert_eq!(form.headers()[CONTENT_TYPE], "application/x-www-form-urlencoded");
+        assert_eq!(body(&form).as_deref(), Some("count=1&name=tempus"));
+
+        let text = build("text", serde_json::json!("hello"));
+        assert_eq!(text.headers()[CONTENT_TYPE], "text/plain; charset=utf-8");
+        assert_eq!(body(&text).as_deref(), Some("hello"));
+
+        let none = build("none", serde_json::json!({"ignored": true}));
+        assert!(none.body().is_none());
+        assert!(none.headers().get(CONTENT_TYPE).is_none());
+    }
+
+    #[test]
+    fn test_add_delivery_headers() {
+        let mut job = job();
+        job.retries = 1;
+        job.occurrences = 4;
+        job.time = job.scheduled_at + chrono::Duration::minutes(2);
+        let now = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut request = build_request(
+            &Client::new(),
+            "https://example.com/hook".to_string(),
+            serde_json::json!({"id": 1}),
+            &JobHttpRequestEntity::default(),
+        )
+        .build()
+        .unwrap();
+
+        add_delivery_headers(&mut request, &job, &["secret".to_string()], now).unwrap();
+
+        let headers = request.headers();
+        assert_eq!(headers[JOB_ID_HEADER], job.id.to_string());
+        assert_eq!(headers[ATTEMPT_HEADER], "2");
+        assert_eq!(headers[SCHEDULED_AT_HEADER], "1970-01-01T00:00:00+00:00");
+        assert_eq!(headers[IDEMPOTENCY_KEY_HEADER], format!("{}:4", job.id));
+        assert_eq!(headers[TIMESTAMP_HEADER], "1700000000");
+        assert_eq!(
+            headers[SIGNATURE_HEADER].to_str().unwrap(),
+            sign(&["secret".to_string()], 1_700_000_000, br#"{"id":1}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_delivery_headers_without_secrets_skips_signature() {
+        let job = job();
+        let mut request = Client::new().get("https://example.com").build().unwrap();
+
+        add_delivery_headers(&mut request, &job, &[], Utc::now()).unwrap();
+
+        assert!(request.headers().contains_key(IDEMPOTENCY_KEY_HEADER));
+        assert!(!request.headers().contains_key(SIGNATURE_HEADER));
+    }
+
+    #[test]
+    fn test_validate_url() {
+        assert!(validate_url("https://example.com").is_ok());
+        assert!(validate_url("http://example.com").is_ok());
+        assert!(validate_url("").is_err());
+        assert!(validate_url("ftp://example.com").is_err());
+        assert!(validate_url("invalid-url").is_err());
+    }
+}
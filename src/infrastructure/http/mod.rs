@@ -0,0 +1,2 @@
+pub mod http_job_executor;
+pub mod webhook_signer;
\ No newline at end of file
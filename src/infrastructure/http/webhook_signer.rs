@@ -0,0 +1,55 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+pub const TIMESTAMP_HEADER: &str = "Tempus-Timestamp";
+pub const SIGNATURE_HEADER: &str = "Tempus-Signature";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs `{timestamp}.{body}` with every secret, so receivers can keep verifying while a key is
+/// rotated. Returns the `Tempus-Signature` header value, e.g. `v1=<hex>,v1=<hex>`.
+pub fn sign(secrets: &[String], timestamp: i64, body: &[u8]) -> Option<String> {
+    let signatures = secrets
+        .iter()
+        .map(|secret| {
+            let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+                .expect("HMAC accepts keys of any length");
+            mac.update(timestamp.to_string().as_bytes());
+            mac.update(b".");
+            mac.update(body);
+            format!("v1={}", hex::encode(mac.finalize().into_bytes()))
+        })
+        .collect::<Vec<_>>();
+
+    (!signatures.is_empty()).then(|| signatures.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_matches_reference_hmac() {
+        // echo -n '1700000000.{"id":1}' | openssl dgst -sha256 -hmac secret
+        assert_eq!(
+            sign(&["secret".to_string()], 1_700_000_000, br#"{"id":1}"#).as_deref(),
+            Some("v1=3dd1b9aef568d75f6790a84bd2e5dfa1f44409eef3cbdbd3f10b837376100c11")
+        );
+    }
+
+    #[test]
+    fn test_sign_with_rotated_secrets() {
+        let signature = sign(&["new".to_string(), "old".to_string()], 1, b"body").unwrap();
+        let parts = signature.split(',').collect::<Vec<_>>();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(Some(parts[0]), sign(&["new".to_string()], 1, b"body").as_deref());
+        assert_eq!(Some(parts[1]), sign(&["old".to_string()], 1, b"body").as_deref());
+        assert_ne!(parts[0], parts[1]);
+    }
+
+    #[test]
+    fn test_sign_without_secrets() {
+        assert_eq!(sign(&[], 1, b"body"), None);
+    }
+}
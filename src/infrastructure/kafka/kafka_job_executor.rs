@@ -0,0 +1,163 @@
+use crate::config::app_config::KafkaConfig;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::entity::job_kafka_message_entity::JobKafkaMessageEntity;
+use crate::domain::job::entity::job_time_entity::parse_timezone;
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::delivery::delivery_headers;
+use crate::infrastructure::kafka::kafka_publisher::{create_kafka_producer, publish_kafka_message};
+use crate::infrastructure::metrics::increment_kafka_messages;
+use rdkafka::producer::FutureProducer;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::OnceCell;
+
+/// Publishes `kafka` jobs to their target topic, or to the default topic when the target is empty.
+/// Each cluster's producer is created on first use and kept for later jobs.
+pub struct KafkaJobExecutor {
+    config: KafkaConfig,
+    producer: OnceCell<FutureProducer>,
+    clusters: HashMap<String, (KafkaConfig, OnceCell<FutureProducer>)>,
+}
+
+impl KafkaJobExecutor {
+    pub fn new(config: KafkaConfig) -> Self {
+        let clusters = config
+            .clusters
+            .keys()
+            .filter_map(|name| Some((name.clone(), (config.cluster(name)?, OnceCell::new()))))
+            .collect();
+
+        Self {
+            config,
+            producer: OnceCell::new(),
+            clusters,
+        }
+    }
+
+    /// The producer and settings of `cluster`, or of the default cluster when it is unset.
+    async fn producer(&self, cluster: Option<&str>) -> Result<(&FutureProducer, &KafkaConfig)> {
+        let (config, producer) = match cluster {
+            None => (&self.config, &self.producer),
+            Some(name) => self
+                .clusters
+                .get(name)
+                .map(|(config, producer)| (config, producer))
+                .ok_or_else(|| TempusError::Kafka(format!("Kafka cluster not found: {}", name)))?,
+        };
+
+        let producer = producer
+            .get_or_try_init(|| async { create_kafka_producer(config) })
+            .await?;
+
+        Ok((producer, config))
+    }
+}
+
+fn with_kafka_delivery_headers(job: &JobEntity) -> Result<JobKafkaMessageEntity> {
+    let mut kafka_message = job.options::<JobKafkaMessageEntity>()?.unwrap_or_default();
+    kafka_message.headers.extend(delivery_headers(job));
+    Ok(kafka_message)
+}
+
+#[async_trait]
+impl JobExecutor for KafkaJobExecutor {
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        let timezone = request.timezone.as_deref().map(parse_timezone).transpose()?;
+        let kafka_message = JobKafkaMessageEntity::from_options(&request.options, timezone)?;
+
+        if let Some(name) = kafka_message.cluster.as_deref().filter(|name| !self.config.clusters.contains_key(*name)) {
+            return Err(TempusError::Validation(format!("Kafka cluster not found: {}", name)));
+        }
+
+        Ok(serde_json::to_value(kafka_message)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let kafka_message = match with_kafka_delivery_headers(job) {
+            Ok(kafka_message) => kafka_message,
+            Err(e) => return (execution, Err(e)),
+        };
+        let (producer, kafka_config) = match self.producer(kafka_message.cluster.as_deref()).await {
+            Ok(producer) => producer,
+            Err(e) => return (execution, Err(e)),
+        };
+        let delivery = publish_kafka_message(
+            producer,
+            kafka_config,
+            job.target.clone(),
+            job.payload.clone(),
+            &kafka_message,
+            job.execution_timeout(),
+        )
+        .await;
+
+        match delivery {
+            Ok((partition, offset)) => {
+                increment_kafka_messages();
+                let execution = JobExecutionEntity {
+                    kafka_partition: Some(partition),
+                    kafka_offset: Some(offset),
+                    ..execution
+                };
+                (execution, Ok(()))
+            }
+            Err(e) => (execution, Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use crate::infrastructure::delivery::{ATTEMPT_HEADER, JOB_ID_HEADER};
+
+    fn executor() -> KafkaJobExecutor {
+        let analytics = serde_json::from_value(serde_json::json!({"bootstrap_servers": "analytics:9092"})).unwrap();
+
+        KafkaJobExecutor::new(KafkaConfig {
+            bootstrap_servers: "localhost:9092".to_string(),
+            default_topic: "tempus-events".to_string(),
+            producer_timeout_secs: 30,
+            producer_retries: 5,
+            batch_size: 16384,
+            compression_type: "none".to_string(),
+            clusters: HashMap::from([("analytics".to_string(), analytics)]),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_producer_per_cluster() {
+        let executor = executor();
+
+        let (default_producer, default_config) = executor.producer(None).await.unwrap();
+        let (analytics_producer, analytics_config) = executor.producer(Some("analytics")).await.unwrap();
+
+        assert_eq!(default_config.bootstrap_servers, "localhost:9092");
+        assert_eq!(analytics_config.bootstrap_servers, "analytics:9092");
+        assert!(!std::ptr::eq(default_producer, analytics_producer));
+        assert!(std::ptr::eq(executor.producer(Some("analytics")).await.unwrap().0, analytics_producer));
+        assert!(matches!(executor.producer(Some("partner")).await, Err(TempusError::Kafka(_))));
+    }
+
+    fn job() -> JobEntity {
+        JobEntity::fixture(JobType::KAFKA, "orders")
+    }
+
+    #[test]
+    fn test_with_kafka_delivery_headers() {
+        let mut job = job();
+        job.retries = 2;
+        job.options = serde_json::json!({"headers": {"trace-id": "abc", JOB_ID_HEADER: "spoofed"}});
+
+        let kafka_message = with_kafka_delivery_headers(&job).unwrap();
+
+        assert_eq!(kafka_message.headers.get("trace-id").map(String::as_str), Some("abc"));
+        assert_eq!(kafka_message.headers.get(JOB_ID_HEADER), Some(&job.id.to_string()));
+        assert_eq!(kafka_message.headers.get(ATTEMPT_HEADER).map(String::as_str), Some("3"));
+    }
+}
@@ -1,35 +1,24 @@
-use crate::config::app_config::AppConfig;
+use crate::config::app_config::KafkaConfig;
+use crate::domain::job::entity::job_kafka_message_entity::JobKafkaMessageEntity;
 use crate::error::{Result, TempusError};
 use log::{error, info};
-use once_cell::sync::Lazy;
 use rdkafka::config::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use rdkafka::util::Timeout;
 use sea_orm::JsonValue;
-use std::sync::Arc;
 
-static KAFKA_PRODUCER: Lazy<Arc<FutureProducer>> = Lazy::new(|| {
-    let config = AppConfig::load().expect("Failed to load configuration");
-    let producer = create_kafka_producer(&config).expect("Failed to create Kafka producer");
-    Arc::new(producer)
-});
-
-pub struct KafkaPublisher {
-    producer: Arc<FutureProducer>,
-    config: AppConfig,
-}
-
-fn create_kafka_producer(config: &AppConfig) -> Result<FutureProducer> {
+pub fn create_kafka_producer(config: &KafkaConfig) -> Result<FutureProducer> {
     let mut client_config = ClientConfig::new();
     
-    info!("Creating Kafka producer with bootstrap servers: {}", config.kafka.bootstrap_servers);
+    info!("Creating Kafka producer with bootstrap servers: {}", config.bootstrap_servers);
     
     client_config
-        .set("bootstrap.servers", &config.kafka.bootstrap_servers)
-        .set("message.timeout.ms", (config.kafka.producer_timeout_secs * 1000).to_string())
-        .set("retries", config.kafka.producer_retries.to_string())
-        .set("batch.size", config.kafka.batch_size.to_string())
-        .set("compression.type", &config.kafka.compression_type)
+        .set("bootstrap.servers", &config.bootstrap_servers)
+        .set("message.timeout.ms", (config.producer_timeout_secs * 1000).to_string())
+        .set("retries", config.producer_retries.to_string())
+        .set("batch.size", config.batch_size.to_string())
+        .set("compression.type", &config.compression_type)
         .set("acks", "all")
         .set("enable.idempotence", "true");
 
@@ -38,66 +27,29 @@ fn create_kafka_producer(config: &AppConfig) -> Result<FutureProducer> {
         .map_err(|e| TempusError::Kafka(e.to_string()))
 }
 
-impl KafkaPublisher {
-    pub fn new(config: &AppConfig) -> Result<Self> {
-        Ok(Self {
-            producer: KAFKA_PRODUCER.clone(),
-            config: config.clone(),
-        })
-    }
-
-    pub fn get_lazy_producer() -> Arc<FutureProducer> {
-        KAFKA_PRODUCER.clone()
-    }
-
-    pub async fn publish_message(&self, topic: &str, payload: JsonValue) -> Result<()> {
-        let payload_str = payload.to_string();
-        
-        info!("Publishing message to Kafka topic: {}", topic);
-        
-        let record: FutureRecord<(), _> = FutureRecord::to(topic)
-            .payload(&payload_str)
-            .timestamp(chrono::Utc::now().timestamp_millis());
-
-        match self.producer
-            .send(record, Timeout::After(self.config.kafka.producer_timeout()))
-            .await
-        {
-            Ok((partition, offset)) => {
-                info!("Message successfully published to topic: {}, partition: {}, offset: {}", 
-                      topic, partition, offset);
-                Ok(())
-            }
-            Err((kafka_error, _)) => {
-                error!("Failed to publish message to Kafka: {}", kafka_error);
-                Err(TempusError::Kafka(kafka_error.to_string()))
-            }
-        }
-    }
-
-    pub async fn publish_to_default_topic(&self, payload: JsonValue) -> Result<()> {
-        self.publish_message(&self.config.kafka.default_topic, payload).await
-    }
-}
-
-pub async fn publish_kafka_message(target: String, payload: JsonValue) -> Result<()> {
-    let config = AppConfig::load()?;
-    let producer = KAFKA_PRODUCER.clone();
-
+/// Publishes through the producer of the cluster `kafka_config` describes. `timeout` overrides
+/// the cluster's producer timeout for this message. Returns the partition and offset the message
+/// was written to.
+pub async fn publish_kafka_message(
+    producer: &FutureProducer,
+    kafka_config: &KafkaConfig,
+    target: String,
+    payload: JsonValue,
+    kafka_message: &JobKafkaMessageEntity,
+    timeout: Option<std::time::Duration>,
+) -> Result<(i32, i64)> {
     let topic = if target.is_empty() {
-        config.kafka.default_topic.clone()
+        kafka_config.default_topic.clone()
     } else {
         target
     };
 
-    let timeout = config.kafka.producer_timeout();
+    let timeout = timeout.unwrap_or(kafka_config.producer_timeout());
     let payload_str = payload.to_string();
     
     info!("Publishing message to Kafka topic: {}", topic);
     
-    let record: FutureRecord<(), _> = FutureRecord::to(&topic)
-        .payload(&payload_str)
-        .timestamp(chrono::Utc::now().timestamp_millis());
+    let record = build_record(&topic, &payload_str, kafka_message);
 
     match producer
         .send(record, Timeout::After(timeout))
@@ -106,11 +58,86 @@ pub async fn publish_kafka_message(target: String, payload: JsonValue) -> Result
         Ok((partition, offset)) => {
             info!("Message successfully published to topic: {}, partition: {}, offset: {}", 
                   topic, partition, offset);
-            Ok(())
+            Ok((partition, offset))
         }
         Err((kafka_error, _)) => {
             error!("Failed to publish message to Kafka: {}", kafka_error);
             Err(TempusError::Kafka(kafka_error.to_string()))
         }
     }
-}
\ No newline at end of file
+}
+
+fn build_record<'a>(
+    topic: &'a str,
+    payload: &'a str,
+    kafka_message: &'a JobKafkaMessageEntity,
+) -> FutureRecord<'a, str, str> {
+    let headers = kafka_message
+        .headers
+        .iter()
+        .fold(OwnedHeaders::new_with_capacity(kafka_message.headers.len()), |headers, (name, value)| {
+            headers.insert(Header {
+                key: name,
+                value: Some(value.as_str()),
+            })
+        });
+
+    let record = FutureRecord::to(topic)
+        .payload(payload)
+        .headers(headers)
+        .timestamp(
+            kafka_message
+                .timestamp
+                .unwrap_or_else(chrono::Utc::now)
+                .timestamp_millis(),
+        );
+    let record = match &kafka_message.key {
+        Some(key) => record.key(key.as_str()),
+        None => record,
+    };
+
+    match kafka_message.partition {
+        Some(partition) => record.partition(partition),
+        None => record,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rdkafka::message::Headers;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_build_record_applies_message_options() {
+        let timestamp = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let kafka_message = JobKafkaMessageEntity::new(
+            None,
+            Some("order-42".to_string()),
+            BTreeMap::from([("trace-id".to_string(), "abc".to_string())]),
+            Some(3),
+            Some(timestamp),
+        )
+        .unwrap();
+
+        let record = build_record("orders", "{}", &kafka_message);
+
+        assert_eq!(record.key, Some("order-42"));
+        assert_eq!(record.partition, Some(3));
+        assert_eq!(record.timestamp, Some(1_700_000_000_000));
+        let headers = record.headers.unwrap();
+        assert_eq!(headers.count(), 1);
+        assert_eq!(headers.get(0).key, "trace-id");
+        assert_eq!(headers.get(0).value, Some("abc".as_bytes()));
+    }
+
+    #[test]
+    fn test_build_record_without_key_or_partition() {
+        let kafka_message = JobKafkaMessageEntity::default();
+        let record = build_record("orders", "{}", &kafka_message);
+
+        assert_eq!(record.key, None);
+        assert_eq!(record.partition, None);
+        assert!(record.timestamp.is_some());
+    }
+}
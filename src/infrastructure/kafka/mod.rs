@@ -1 +1,2 @@
+pub mod kafka_job_executor;
 pub mod kafka_publisher;
\ No newline at end of file
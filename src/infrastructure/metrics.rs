@@ -16,8 +16,19 @@ fn initialize_counters() {
     counter!("jobs_processed_total", "status" => "success").absolute(0);
     counter!("jobs_processed_total", "status" => "failure").absolute(0);
     counter!("jobs_processed_total", "status" => "retry").absolute(0);
+    counter!("jobs_processed_total", "status" => "skipped").absolute(0);
+    counter!("jobs_processed_total", "status" => "expired").absolute(0);
     counter!("jobs_http_requests_total", "status_code" => "200").absolute(0);
     counter!("jobs_kafka_messages_total").absolute(0);
+    counter!("jobs_amqp_messages_total").absolute(0);
+    counter!("jobs_nats_messages_total", "acked" => "true").absolute(0);
+    counter!("jobs_nats_messages_total", "acked" => "false").absolute(0);
+    counter!("jobs_redis_messages_total").absolute(0);
+    counter!("jobs_aws_messages_total", "service" => "sqs").absolute(0);
+    counter!("jobs_aws_messages_total", "service" => "sns").absolute(0);
+    counter!("jobs_commands_total", "exit_code" => "0").absolute(0);
+    counter!("jobs_emails_total").absolute(0);
+    counter!("jobs_grpc_requests_total", "code" => "0").absolute(0);
     histogram!("jobs_duration_seconds").record(0.0);
     gauge!("current_processing_jobs").set(0.0);
 }
@@ -99,6 +110,66 @@ pub fn increment_kafka_messages() {
     log_and_increment_simple_counter("jobs_kafka_messages_total", "Incrementing Kafka messages counter");
 }
 
+pub fn increment_amqp_messages() {
+    log_and_increment_simple_counter("jobs_amqp_messages_total", "Incrementing AMQP messages counter");
+}
+
+/// `acked` tells JetStream-acknowledged messages from plain publishes.
+pub fn increment_nats_messages(acked: bool) {
+    log_and_increment_counter(
+        "jobs_nats_messages_total",
+        "acked",
+        acked.to_string(),
+        &format!("Incrementing NATS messages counter, acked: {}", acked)
+    );
+}
+
+pub fn increment_redis_messages() {
+    log_and_increment_simple_counter("jobs_redis_messages_total", "Incrementing Redis messages counter");
+}
+
+pub fn increment_aws_messages(service: &str) {
+    log_and_increment_counter(
+        "jobs_aws_messages_total",
+        "service",
+        service.to_string(),
+        &format!("Incrementing AWS messages counter for service: {}", service)
+    );
+}
+
+/// Commands killed by a signal have no exit code and are counted as `signal`.
+pub fn increment_commands(exit_code: Option<i32>) {
+    let exit_code = exit_code.map_or_else(|| "signal".to_string(), |exit_code| exit_code.to_string());
+    log_and_increment_counter(
+        "jobs_commands_total",
+        "exit_code",
+        exit_code.clone(),
+        &format!("Incrementing commands with exit code: {}", exit_code)
+    );
+}
+
+pub fn increment_emails() {
+    log_and_increment_simple_counter("jobs_emails_total", "Incrementing emails counter");
+}
+
+pub fn increment_grpc_requests(code: i32) {
+    log_and_increment_counter(
+        "jobs_grpc_requests_total",
+        "code",
+        code.to_string(),
+        &format!("Incrementing gRPC requests with status code: {}", code)
+    );
+}
+
+pub fn increment_sql_statements(statement: &str) {
+    log_and_increment_counter(
+        "jobs_sql_statements_total",
+        "statement",
+        statement.to_string(),
+        &format!("Incrementing SQL statements counter for statement: {}", statement)
+    );
+}
+
 pub fn set_current_processing_jobs(count: i64) {
     gauge!("current_processing_jobs").set(count as f64);
 }
@@ -121,6 +192,14 @@ mod tests {
         observe_job_duration(1.5);
         increment_http_requests(200);
         increment_kafka_messages();
+        increment_amqp_messages();
+        increment_nats_messages(true);
+        increment_redis_messages();
+        increment_aws_messages("sqs");
+        increment_commands(Some(0));
+        increment_emails();
+        increment_sql_statements("purge_sessions");
+        increment_grpc_requests(0);
         set_current_processing_jobs(5);
         increment_current_processing_jobs();
         decrement_current_processing_jobs();
@@ -1,4 +1,14 @@
 pub mod persistence;
+pub mod amqp;
+pub mod aws;
+pub mod command;
+pub mod delivery;
+pub mod email;
+pub mod grpc;
 pub mod kafka;
+pub mod http;
 pub mod metrics;
+pub mod nats;
+pub mod redis;
+pub mod sql;
 pub mod metrics_server;
\ No newline at end of file
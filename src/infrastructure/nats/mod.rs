@@ -0,0 +1,2 @@
+pub mod nats_job_executor;
+pub mod nats_publisher;
@@ -0,0 +1,99 @@
+use crate::config::app_config::NatsConfig;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::entity::job_nats_message_entity::JobNatsMessageEntity;
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::Result;
+use crate::infrastructure::delivery::{delivery_headers, idempotency_key};
+use crate::infrastructure::metrics::increment_nats_messages;
+use crate::infrastructure::nats::nats_publisher::NatsPublisher;
+use log::debug;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// JetStream drops messages whose id it has already stored within the stream's duplicate window.
+const MESSAGE_ID_HEADER: &str = "Nats-Msg-Id";
+
+/// Publishes `nats` jobs to the subject named by their target.
+pub struct NatsJobExecutor {
+    publisher: NatsPublisher,
+}
+
+impl NatsJobExecutor {
+    pub fn new(config: NatsConfig) -> Self {
+        Self {
+            publisher: NatsPublisher::new(config),
+        }
+    }
+}
+
+fn with_nats_delivery_headers(job: &JobEntity) -> Result<BTreeMap<String, String>> {
+    let mut headers = job
+        .options::<JobNatsMessageEntity>()?
+        .map(|nats_message| nats_message.headers)
+        .unwrap_or_default();
+    headers.extend(delivery_headers(job));
+    headers.insert(MESSAGE_ID_HEADER.to_string(), idempotency_key(job));
+    Ok(headers)
+}
+
+#[async_trait]
+impl JobExecutor for NatsJobExecutor {
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        Ok(serde_json::to_value(JobNatsMessageEntity::from_options(&request.options, &request.target)?)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let headers = match with_nats_delivery_headers(job) {
+            Ok(headers) => headers,
+            Err(e) => return (execution, Err(e)),
+        };
+
+        let result = self
+            .publisher
+            .publish(&job.target, &job.payload, &headers, job.execution_timeout())
+            .await
+            .map(|ack| {
+                if let Some(ack) = &ack {
+                    debug!("Job {} stored in stream {} at sequence {}", job.id, ack.stream, ack.sequence);
+                }
+                increment_nats_messages(ack.is_some());
+            });
+
+        (execution, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+    use crate::infrastructure::delivery::{ATTEMPT_HEADER, JOB_ID_HEADER};
+
+    #[test]
+    fn test_with_nats_delivery_headers() {
+        let nats_message = JobNatsMessageEntity::new(
+            "orders.created",
+            BTreeMap::from([
+                ("Trace-Id".to_string(), "abc".to_string()),
+                (JOB_ID_HEADER.to_string(), "spoofed".to_string()),
+            ]),
+        )
+        .unwrap();
+        let job = JobEntity {
+            retries: 1,
+            options: serde_json::to_value(nats_message).unwrap(),
+            occurrences: 3,
+            ..JobEntity::fixture(JobType::NATS, "orders.created")
+        };
+
+        let headers = with_nats_delivery_headers(&job).unwrap();
+
+        assert_eq!(headers.get("Trace-Id").map(String::as_str), Some("abc"));
+        assert_eq!(headers.get(JOB_ID_HEADER), Some(&job.id.to_string()));
+        assert_eq!(headers.get(ATTEMPT_HEADER).map(String::as_str), Some("2"));
+        assert_eq!(headers.get(MESSAGE_ID_HEADER), Some(&format!("{}:3", job.id)));
+    }
+}
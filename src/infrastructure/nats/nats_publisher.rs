@@ -0,0 +1,145 @@
+use crate::config::app_config::NatsConfig;
+use crate::error::{Result, TempusError};
+use async_nats::jetstream::context::PublishErrorKind;
+use async_nats::jetstream::publish::PublishAck;
+use async_nats::{Client, HeaderMap};
+use log::{debug, info};
+use sea_orm::JsonValue;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+/// Publishes over a single client connection, opened on first use. The client reconnects on
+/// its own once connected.
+pub struct NatsPublisher {
+    config: NatsConfig,
+    client: OnceCell<Client>,
+}
+
+impl NatsPublisher {
+    pub fn new(config: NatsConfig) -> Self {
+        Self {
+            config,
+            client: OnceCell::new(),
+        }
+    }
+
+    async fn client(&self) -> Result<&Client> {
+        self.client
+            .get_or_try_init(|| async {
+                info!("Connecting to NATS server");
+                async_nats::connect(&self.config.url)
+                    .await
+                    .map_err(|e| TempusError::Nats(e.to_string()))
+            })
+            .await
+    }
+
+    /// Returns the JetStream ack, or `None` when the message went out as a plain publish because
+    /// JetStream is disabled or no stream captures the subject.
+    pub async fn publish(
+        &self,
+        subject: &str,
+        payload: &JsonValue,
+        headers: &BTreeMap<String, String>,
+        timeout: Option<Duration>,
+    ) -> Result<Option<PublishAck>> {
+        let timeout = timeout.unwrap_or(self.config.publish_timeout());
+
+        tokio::time::timeout(timeout, self.publish_acked(subject, payload, headers, timeout))
+            .await
+            .map_err(|_| TempusError::Timeout(timeout))?
+    }
+
+    async fn publish_acked(
+        &self,
+        subject: &str,
+        payload: &JsonValue,
+        headers: &BTreeMap<String, String>,
+        timeout: Duration,
+    ) -> Result<Option<PublishAck>> {
+        let client = self.client().await?;
+        let body = serde_json::to_vec(payload)?;
+        let headers = build_headers(headers);
+
+        if self.config.jetstream {
+            let mut jetstream = async_nats::jetstream::new(client.clone());
+            jetstream.set_timeout(timeout);
+
+            let ack = jetstream
+                .publish_with_headers(subject.to_string(), headers.clone(), body.clone().into())
+                .await
+                .map_err(|e| TempusError::Nats(e.to_string()))?
+                .await;
+
+            match ack {
+                Ok(ack) => return Ok(Some(ack)),
+                Err(e) if e.kind() == PublishErrorKind::StreamNotFound => {
+                    debug!("No JetStream stream captures subject {}, publishing without ack", subject);
+                }
+                Err(e) => return Err(TempusError::Nats(e.to_string())),
+            }
+        }
+
+        client
+            .publish_with_headers(subject.to_string(), headers, body.into())
+            .await
+            .map_err(|e| TempusError::Nats(e.to_string()))?;
+        client.flush().await.map_err(|e| TempusError::Nats(e.to_string()))?;
+
+        Ok(None)
+    }
+}
+
+fn build_headers(headers: &BTreeMap<String, String>) -> HeaderMap {
+    headers.iter().fold(HeaderMap::new(), |mut header_map, (name, value)| {
+        header_map.insert(name.as_str(), value.as_str());
+        header_map
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_headers() {
+        let headers = build_headers(&BTreeMap::from([("Trace-Id".to_string(), "abc".to_string())]));
+
+        assert_eq!(headers.get("Trace-Id").map(|value| value.as_str()), Some("abc"));
+    }
+
+    /// Run with `cargo test -- --ignored` against the NATS service in docker-compose.yml.
+    #[tokio::test]
+    #[ignore = "requires a local NATS server with JetStream"]
+    async fn test_publish_to_local_server() {
+        let config = NatsConfig {
+            url: std::env::var("NATS_URL").unwrap_or_else(|_| "nats://localhost:4222".to_string()),
+            jetstream: true,
+            publish_timeout_secs: 5,
+        };
+        let publisher = NatsPublisher::new(config);
+        let client = publisher.client().await.unwrap().clone();
+        let stream_name = format!("tempus-test-{}", uuid::Uuid::new_v4().simple());
+        let subject = format!("{}.orders", stream_name);
+        let jetstream = async_nats::jetstream::new(client.clone());
+        jetstream
+            .create_stream(async_nats::jetstream::stream::Config {
+                name: stream_name.clone(),
+                subjects: vec![subject.clone()],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let payload = serde_json::json!({"event": "order.created"});
+        let ack = publisher.publish(&subject, &payload, &BTreeMap::new(), None).await.unwrap();
+        assert_eq!(ack.map(|ack| ack.stream), Some(stream_name.clone()));
+
+        let uncaptured_subject = format!("{}.uncaptured", stream_name);
+        let ack = publisher.publish(&uncaptured_subject, &payload, &BTreeMap::new(), None).await.unwrap();
+        assert!(ack.is_none());
+
+        jetstream.delete_stream(&stream_name).await.unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
+use crate::domain::calendar::port::driven::calendar_repository_port::CalendarRepositoryPort;
+use crate::domain::calendar::r#enum::calendar_enum::CalendarDeletion;
+use crate::infrastructure::persistence::calendar::business_calendar;
+use crate::infrastructure::persistence::job::prelude::Job;
+use crate::infrastructure::persistence::job::sea_orm_active_enums::JobStatusEnum;
+use crate::infrastructure::persistence::job::{job, job_metadata};
+use chrono::Utc;
+use sea_orm::prelude::async_trait::async_trait;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, JoinType, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait, Set, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct CalendarRepository {
+    db: DatabaseConnection,
+}
+
+impl CalendarRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CalendarRepositoryPort for CalendarRepository {
+    async fn find_all(&self) -> Result<Vec<CalendarEntity>, DbErr> {
+        let calendars = business_calendar::Entity::find()
+            .order_by_asc(business_calendar::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Ok(calendars.into_iter().map(CalendarEntity::from).collect())
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<CalendarEntity>, DbErr> {
+        let calendar = business_calendar::Entity::find_by_id(name.to_string())
+            .one(&self.db)
+            .await?;
+
+        Ok(calendar.map(CalendarEntity::from))
+    }
+
+    async fn save(&self, calendar: &CalendarEntity) -> Result<(), DbErr> {
+        let to_json = |value: serde_json::Result<serde_json::Value>| {
+            value.map_err(|e| DbErr::Custom(e.to_string()))
+        };
+
+        let calendar_active_model = business_calendar::ActiveModel {
+            name: Set(calendar.name.clone()),
+            working_days: Set(to_json(serde_json::to_value(&calendar.working_days))?),
+            holidays: Set(to_json(serde_json::to_value(&calendar.holidays))?),
+            timezone: Set(calendar.timezone.map(|timezone| timezone.name().to_string())),
+            created_at: Set(Utc::now().naive_utc()),
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        business_calendar::Entity::insert(calendar_active_model)
+            .on_conflict(
+                OnConflict::column(business_calendar::Column::Name)
+                    .update_columns([
+                        business_calendar::Column::WorkingDays,
+                        business_calendar::Column::Holidays,
+                        business_calendar::Column::Timezone,
+                        business_calendar::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_unused(&self, name: &str) -> Result<CalendarDeletion, DbErr> {
+        let txn = self.db.begin().await?;
+
+        // Jobs inserted with this calendar wait on the row lock, then fail their foreign key.
+        let calendar = business_calendar::Entity::find_by_id(name.to_string())
+            .lock_exclusive()
+            .one(&txn)
+            .await?;
+        if calendar.is_none() {
+            txn.rollback().await?;
+            return Ok(CalendarDeletion::NotFound);
+        }
+
+        let active_jobs = Job::find()
+            .join(JoinType::InnerJoin, job::Relation::JobMetadata.def())
+            .filter(job::Column::Calendar.eq(name))
+            .filter(job_metadata::Column::Status.is_in([
+                JobStatusEnum::Scheduled,
+                JobStatusEnum::Processing,
+            ]))
+            .count(&txn)
+            .await?;
+        if active_jobs > 0 {
+            txn.rollback().await?;
+            return Ok(CalendarDeletion::InUse(active_jobs));
+        }
+
+        business_calendar::Entity::delete_by_id(name.to_string())
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(CalendarDeletion::Deleted)
+    }
+}
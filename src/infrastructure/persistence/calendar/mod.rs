@@ -0,0 +1,2 @@
+pub mod business_calendar;
+pub mod calendar_repository;
@@ -0,0 +1,198 @@
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::entity::job_metadata_entity::JobMetadataEntity;
+use crate::domain::job::port::driven::job_execution_repository_port::JobExecutionRepositoryPort;
+use crate::domain::job::port::driven::job_metadata_repository_port::JobMetadataRepositoryPort;
+use crate::domain::job::port::driven::job_repository_port::JobRepositoryPort;
+use crate::domain::job::r#enum::job_enum::JobMetadataStatus;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::async_trait::async_trait;
+use sea_orm::prelude::Uuid;
+use sea_orm::DbErr;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Keeps jobs in memory for use case tests, claiming and updating them the way the SQL
+/// repositories do. Executions are not kept.
+#[derive(Clone, Default)]
+pub struct InMemoryJobRepository {
+    jobs: Arc<Mutex<HashMap<Uuid, JobEntity>>>,
+}
+
+impl InMemoryJobRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_job(job: JobEntity) -> Self {
+        let repository = Self::new();
+        repository.jobs.lock().unwrap().insert(job.id, job);
+        repository
+    }
+
+    pub fn jobs(&self) -> Vec<JobEntity> {
+        self.jobs.lock().unwrap().values().cloned().collect()
+    }
+
+    pub fn job(&self, job_id: Uuid) -> Option<JobEntity> {
+        self.jobs.lock().unwrap().get(&job_id).cloned()
+    }
+
+    fn update<F: FnOnce(&mut JobEntity)>(&self, job_id: Uuid, update: F) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(&job_id) {
+            update(job);
+        }
+    }
+}
+
+fn is_scheduled(job: &JobEntity) -> bool {
+    job.metadata
+        .as_ref()
+        .is_some_and(|metadata| matches!(metadata.status, JobMetadataStatus::Scheduled))
+}
+
+#[async_trait]
+impl JobRepositoryPort for InMemoryJobRepository {
+    async fn find_all(&self) -> Result<Vec<JobEntity>, DbErr> {
+        Ok(self
+            .jobs()
+            .into_iter()
+            .filter(|job| job.time <= Utc::now() && is_scheduled(job))
+            .collect())
+    }
+
+    /// Unlike the SQL repository, due times are ignored so tests can run retries right away.
+    async fn find_and_flag_processing(&self, limit: usize, max_retries: i32) -> Result<Vec<JobEntity>, DbErr> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let mut claimed = jobs
+            .values_mut()
+            .filter(|job| is_scheduled(job) && job.retries <= max_retries)
+            .collect::<Vec<_>>();
+        claimed.sort_by_key(|job| job.time);
+
+        Ok(claimed
+            .into_iter()
+            .take(limit)
+            .map(|job| {
+                if let Some(metadata) = job.metadata.as_mut() {
+                    metadata.status = JobMetadataStatus::Processing;
+                }
+                job.clone()
+            })
+            .collect())
+    }
+
+    async fn find_by_id(&self, job_id: Uuid) -> Result<Option<JobEntity>, DbErr> {
+        Ok(self.job(job_id))
+    }
+
+    async fn increment_retry(&self, job_id: Uuid) -> Result<(), DbErr> {
+        self.update(job_id, |job| job.retries += 1);
+        Ok(())
+    }
+
+    async fn update_time(&self, job_id: Uuid, time: DateTime<Utc>) -> Result<(), DbErr> {
+        self.update(job_id, |job| job.time = time);
+        Ok(())
+    }
+
+    async fn handle_retry_transaction(&self, job_id: Uuid, new_time: DateTime<Utc>, retry_metadata: JobMetadataEntity) -> Result<(), DbErr> {
+        self.update(job_id, |job| {
+            job.retries += 1;
+            job.time = new_time;
+            job.metadata = Some(retry_metadata);
+        });
+        Ok(())
+    }
+
+    async fn handle_reschedule_transaction(&self, job_id: Uuid, new_time: DateTime<Utc>, reschedule_metadata: JobMetadataEntity) -> Result<(), DbErr> {
+        self.update(job_id, |job| {
+            job.time = new_time;
+            job.metadata = Some(reschedule_metadata);
+        });
+        Ok(())
+    }
+
+    async fn handle_occurrence_transaction(&self, job_id: Uuid, next_time: Option<DateTime<Utc>>, occurrence_metadata: JobMetadataEntity) -> Result<(), DbErr> {
+        self.update(job_id, |job| {
+            job.occurrences += 1;
+            if let Some(next_time) = next_time {
+                job.retries = 0;
+                job.time = next_time;
+                job.scheduled_at = next_time;
+            }
+            job.metadata = Some(occurrence_metadata);
+        });
+        Ok(())
+    }
+
+    async fn save(&self, job_entity: &JobEntity) -> Result<(), DbErr> {
+        let job = JobEntity {
+            metadata: Some(JobMetadataEntity {
+                job_id: job_entity.id,
+                status: JobMetadataStatus::Scheduled,
+                failure: None,
+                processed_at: None,
+            }),
+            ..job_entity.clone()
+        };
+        self.jobs.lock().unwrap().insert(job.id, job);
+        Ok(())
+    }
+
+    async fn delete_unprocessed(&self, job_id: Uuid) -> Result<bool, DbErr> {
+        let mut jobs = self.jobs.lock().unwrap();
+        if !jobs.get(&job_id).is_some_and(is_scheduled) {
+            return Ok(false);
+        }
+
+        jobs.remove(&job_id);
+        Ok(true)
+    }
+
+    async fn update_time_unprocessed(&self, job_id: Uuid, time: DateTime<Utc>) -> Result<bool, DbErr> {
+        let mut jobs = self.jobs.lock().unwrap();
+        match jobs.get_mut(&job_id).filter(|job| is_scheduled(job)) {
+            Some(job) => {
+                job.time = time;
+                job.scheduled_at = time;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn postpone_unprocessed<F>(&self, job_id: Uuid, postpone: F) -> crate::error::Result<Option<DateTime<Utc>>>
+    where
+        F: FnOnce(&JobEntity) -> crate::error::Result<DateTime<Utc>> + Send,
+    {
+        let mut jobs = self.jobs.lock().unwrap();
+        let Some(job) = jobs.get_mut(&job_id).filter(|job| is_scheduled(job)) else {
+            return Ok(None);
+        };
+
+        let time = postpone(job)?;
+        job.time = time;
+        job.scheduled_at = time;
+        Ok(Some(time))
+    }
+}
+
+#[async_trait]
+impl JobMetadataRepositoryPort for InMemoryJobRepository {
+    async fn update_status(&self, job_metadata: JobMetadataEntity) -> Result<(), DbErr> {
+        self.update(job_metadata.job_id, |job| job.metadata = Some(job_metadata));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl JobExecutionRepositoryPort for InMemoryJobRepository {
+    async fn save(&self, _execution: &JobExecutionEntity) -> Result<(), DbErr> {
+        Ok(())
+    }
+
+    async fn find_by_job_id(&self, _job_id: Uuid, _after: Option<Uuid>, _limit: u64) -> Result<Vec<JobExecutionEntity>, DbErr> {
+        Ok(vec![])
+    }
+}
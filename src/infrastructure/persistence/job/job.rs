@@ -1,6 +1,5 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.8
 
-use super::sea_orm_active_enums::ScheduleTypeEnum;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
@@ -8,12 +7,26 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
-    pub time: DateTime,
+    pub time: DateTimeWithTimeZone,
+    pub scheduled_at: DateTimeWithTimeZone,
     pub target: String,
     pub retries: i32,
     #[sea_orm(column_type = "JsonBinary")]
     pub payload: Json,
-    pub r#type: ScheduleTypeEnum,
+    pub r#type: String,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub options: Option<Json>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub schedule: Option<Json>,
+    pub occurrences: i32,
+    pub max_occurrences: Option<i32>,
+    pub ends_at: Option<DateTimeWithTimeZone>,
+    pub misfire_policy: Option<String>,
+    pub expires_at: Option<DateTimeWithTimeZone>,
+    pub calendar: Option<String>,
+    pub calendar_roll: Option<String>,
+    pub jitter_secs: Option<i64>,
+    pub timeout_secs: Option<i64>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
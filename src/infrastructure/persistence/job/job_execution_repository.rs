@@ -0,0 +1,80 @@
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::port::driven::job_execution_repository_port::JobExecutionRepositoryPort;
+use crate::infrastructure::persistence::job::job_execution;
+use sea_orm::prelude::async_trait::async_trait;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct JobExecutionRepository {
+    db: DatabaseConnection,
+}
+
+impl JobExecutionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl JobExecutionRepositoryPort for JobExecutionRepository {
+    async fn save(&self, execution: &JobExecutionEntity) -> Result<(), DbErr> {
+        let response_headers = execution
+            .response_headers
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+        let execution_active_model = job_execution::ActiveModel {
+            id: Set(execution.id),
+            job_id: Set(execution.job_id),
+            attempt: Set(execution.attempt),
+            occurrence: Set(execution.occurrence),
+            executor: Set(execution.executor.clone()),
+            started_at: Set(execution.started_at.into()),
+            finished_at: Set(execution.finished_at.into()),
+            outcome: Set(execution.outcome.to_string()),
+            status_code: Set(execution.status_code),
+            kafka_partition: Set(execution.kafka_partition),
+            kafka_offset: Set(execution.kafka_offset),
+            response_headers: Set(response_headers),
+            response_body: Set(execution.response_body.clone()),
+            error: Set(execution.error.clone()),
+            exit_code: Set(execution.exit_code),
+            stdout: Set(execution.stdout.clone()),
+            stderr: Set(execution.stderr.clone()),
+            rows_affected: Set(execution.rows_affected),
+        };
+
+        job_execution::Entity::insert(execution_active_model)
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_job_id(&self, job_id: Uuid, after: Option<Uuid>, limit: u64) -> Result<Vec<JobExecutionEntity>, DbErr> {
+        let mut query = job_execution::Entity::find()
+            .filter(job_execution::Column::JobId.eq(job_id))
+            .order_by_asc(job_execution::Column::StartedAt)
+            .order_by_asc(job_execution::Column::Id)
+            .limit(limit);
+
+        // Keyset on (started_at, id), so pages stay stable while new attempts are recorded.
+        if let Some(after) = after {
+            query = query.filter(Expr::cust_with_values(
+                "(started_at, id) > (SELECT started_at, id FROM job_execution WHERE id = $1)",
+                [after],
+            ));
+        }
+
+        query
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(JobExecutionEntity::try_from)
+            .collect()
+    }
+}
@@ -42,5 +42,7 @@ fn to_model_status(status: JobMetadataStatus) -> JobStatusEnum {
         JobMetadataStatus::Completed => JobStatusEnum::Completed,
         JobMetadataStatus::Deleted => JobStatusEnum::Deleted,
         JobMetadataStatus::Failed => JobStatusEnum::Failed,
+        JobMetadataStatus::Skipped => JobStatusEnum::Skipped,
+        JobMetadataStatus::Expired => JobStatusEnum::Expired,
     }
 }
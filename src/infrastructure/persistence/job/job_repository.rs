@@ -1,18 +1,20 @@
+use crate::domain::calendar::entity::calendar_entity::CalendarEntity;
 use crate::domain::job::entity::job_entity::JobEntity;
 use crate::domain::job::entity::job_metadata_entity::JobMetadataEntity;
 use crate::domain::job::r#enum::job_enum::JobMetadataStatus;
-use crate::domain::job::r#enum::job_enum::JobType;
 use crate::domain::job::port::driven::job_repository_port::JobRepositoryPort;
+use crate::error::TempusError;
+use crate::infrastructure::persistence::calendar::business_calendar;
 use crate::infrastructure::persistence::job::prelude::Job;
 use crate::infrastructure::persistence::job::sea_orm_active_enums::JobStatusEnum;
-use crate::infrastructure::persistence::job::sea_orm_active_enums::ScheduleTypeEnum;
 use crate::infrastructure::persistence::job::{job, job_metadata};
-use chrono::{NaiveDateTime, Utc};
+use chrono::{DateTime, Utc};
 use sea_orm::prelude::Uuid;
+use std::collections::HashMap;
 use sea_orm::prelude::async_trait::async_trait;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr,
-    EntityTrait, IntoActiveModel, QueryFilter, Set, Statement, TransactionTrait,
+    EntityTrait, IntoActiveModel, QueryFilter, QuerySelect, Set, Statement, TransactionTrait,
 };
 
 #[derive(Clone)]
@@ -30,17 +32,15 @@ impl JobRepository {
 impl JobRepositoryPort for JobRepository {
     async fn find_all(&self) -> Result<Vec<JobEntity>, DbErr> {
         let rows = Job::find()
-            .filter(job::Column::Time.lte(Utc::now().naive_utc()))
+            .filter(job::Column::Time.lte(Utc::now()))
             .find_also_related(job_metadata::Entity)
             .filter(job_metadata::Column::Status.eq(JobStatusEnum::Scheduled))
             .all(&self.db)
             .await?;
 
-        let jobs: Vec<JobEntity> = rows.into_iter().map(JobEntity::from).collect();
-
-        Ok(jobs)
+        with_calendars(&self.db, rows).await
     }
-    async fn find_and_flag_processing(&self, limit: usize) -> Result<Vec<JobEntity>, DbErr> {
+    async fn find_and_flag_processing(&self, limit: usize, max_retries: i32) -> Result<Vec<JobEntity>, DbErr> {
         let txn = self.db.begin().await?;
 
         let sql = r#"
@@ -50,7 +50,7 @@ impl JobRepositoryPort for JobRepository {
             SELECT job.id
             FROM job
             INNER JOIN job_metadata ON job.id = job_metadata.job_id
-            WHERE job_metadata.status = 'scheduled' AND job.time <= NOW() AND job.retries < 3
+            WHERE job_metadata.status = 'scheduled' AND job.time <= NOW() AND job.retries <= $2
         ORDER BY job.time ASC
         FOR UPDATE SKIP LOCKED
         LIMIT $1
@@ -62,7 +62,7 @@ impl JobRepositoryPort for JobRepository {
             .query_all(Statement::from_sql_and_values(
                 DatabaseBackend::Postgres,
                 sql,
-                vec![(limit as i32).into()],
+                vec![(limit as i32).into(), max_retries.into()],
             ))
             .await?;
 
@@ -76,15 +76,26 @@ impl JobRepositoryPort for JobRepository {
             return Ok(vec![]);
         }
 
-        let jobs = Job::find()
+        let rows = Job::find()
             .filter(job::Column::Id.is_in(job_ids))
             .find_also_related(job_metadata::Entity)
             .all(&txn)
             .await?;
 
+        let jobs = with_calendars(&txn, rows).await?;
+
         txn.commit().await?;
 
-        Ok(jobs.into_iter().map(JobEntity::from).collect())
+        Ok(jobs)
+    }
+
+    async fn find_by_id(&self, job_id: Uuid) -> Result<Option<JobEntity>, DbErr> {
+        let rows = Job::find_by_id(job_id)
+            .find_also_related(job_metadata::Entity)
+            .all(&self.db)
+            .await?;
+
+        Ok(with_calendars(&self.db, rows).await?.into_iter().next())
     }
 
     async fn increment_retry(&self, job_id: Uuid) -> Result<(), DbErr> {
@@ -97,14 +108,14 @@ impl JobRepositoryPort for JobRepository {
         Ok(())
     }
 
-    async fn update_time(&self, job_id: Uuid, time: NaiveDateTime) -> Result<(), DbErr> {
+    async fn update_time(&self, job_id: Uuid, time: DateTime<Utc>) -> Result<(), DbErr> {
         if let Some(model) = Job::find()
             .filter(job::Column::Id.eq(job_id))
             .one(&self.db)
             .await?
         {
             let mut active_model = model.into_active_model();
-            active_model.time = Set(time);
+            active_model.time = Set(time.into());
             active_model.update(&self.db).await?;
         }
 
@@ -114,7 +125,7 @@ impl JobRepositoryPort for JobRepository {
     async fn handle_retry_transaction(
         &self,
         job_id: Uuid,
-        new_time: NaiveDateTime,
+        new_time: DateTime<Utc>,
         retry_metadata: JobMetadataEntity,
     ) -> Result<(), DbErr> {
         let txn = self.db.begin().await?;
@@ -122,7 +133,7 @@ impl JobRepositoryPort for JobRepository {
         if let Some(job) = Job::find_by_id(job_id).one(&txn).await? {
             let mut active_model = job.into_active_model();
             active_model.retries = Set(active_model.retries.unwrap() + 1);
-            active_model.time = Set(new_time);
+            active_model.time = Set(new_time.into());
             active_model.update(&txn).await?;
         }
 
@@ -139,17 +150,97 @@ impl JobRepositoryPort for JobRepository {
         Ok(())
     }
 
+    async fn handle_reschedule_transaction(
+        &self,
+        job_id: Uuid,
+        new_time: DateTime<Utc>,
+        reschedule_metadata: JobMetadataEntity,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        if let Some(job) = Job::find_by_id(job_id).one(&txn).await? {
+            let mut active_model = job.into_active_model();
+            active_model.time = Set(new_time.into());
+            active_model.updated_at = Set(Utc::now().naive_utc());
+            active_model.update(&txn).await?;
+        }
+
+        let to_update = job_metadata::ActiveModel {
+            job_id: Set(reschedule_metadata.job_id),
+            status: Set(to_model_status(reschedule_metadata.status)),
+            processed_at: Set(reschedule_metadata.processed_at),
+            failure: Set(reschedule_metadata.failure),
+        };
+
+        job_metadata::Entity::update(to_update).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn handle_occurrence_transaction(
+        &self,
+        job_id: Uuid,
+        next_time: Option<DateTime<Utc>>,
+        occurrence_metadata: JobMetadataEntity,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        if let Some(job) = Job::find_by_id(job_id).one(&txn).await? {
+            let mut active_model = job.into_active_model();
+            active_model.occurrences = Set(active_model.occurrences.unwrap() + 1);
+            if let Some(next_time) = next_time {
+                active_model.retries = Set(0);
+                active_model.time = Set(next_time.into());
+                active_model.scheduled_at = Set(next_time.into());
+            }
+            active_model.updated_at = Set(Utc::now().naive_utc());
+            active_model.update(&txn).await?;
+        }
+
+        let to_update = job_metadata::ActiveModel {
+            job_id: Set(occurrence_metadata.job_id),
+            status: Set(to_model_status(occurrence_metadata.status)),
+            processed_at: Set(occurrence_metadata.processed_at),
+            failure: Set(occurrence_metadata.failure),
+        };
+
+        job_metadata::Entity::update(to_update).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+
     async fn save(&self, job_entity: &JobEntity) -> Result<(), DbErr> {
+        let schedule = job_entity
+            .schedule
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+
         let job_active_model = job::ActiveModel {
             id: Set(job_entity.id),
-            time: Set(job_entity.time),
+            time: Set(job_entity.time.into()),
+            scheduled_at: Set(job_entity.scheduled_at.into()),
             target: Set(job_entity.target.clone()),
             retries: Set(job_entity.retries),
-            r#type: Set(match job_entity.r#type {
-                JobType::Http => ScheduleTypeEnum::Http,
-                JobType::Kafka => ScheduleTypeEnum::Kafka,
-            }),
+            r#type: Set(job_entity.r#type.to_string()),
             payload: Set(job_entity.payload.clone()),
+            options: Set((!job_entity.options.is_null()).then(|| job_entity.options.clone())),
+            schedule: Set(schedule),
+            occurrences: Set(job_entity.occurrences),
+            max_occurrences: Set(job_entity.max_occurrences),
+            ends_at: Set(job_entity.ends_at.map(Into::into)),
+            misfire_policy: Set(job_entity.misfire_policy.map(|misfire_policy| misfire_policy.to_string())),
+            expires_at: Set(job_entity.expires_at.map(Into::into)),
+            calendar: Set(job_entity.calendar.as_ref().map(|calendar| calendar.name.clone())),
+            calendar_roll: Set(job_entity
+                .calendar
+                .as_ref()
+                .map(|_| job_entity.calendar_roll.to_string())),
+            jitter_secs: Set(job_entity.jitter.map(|jitter| jitter.num_seconds())),
+            timeout_secs: Set(job_entity.timeout.map(|timeout| timeout.num_seconds())),
             created_at: Set(Utc::now().naive_utc()),
             updated_at: Set(Utc::now().naive_utc()),
         };
@@ -191,7 +282,7 @@ impl JobRepositoryPort for JobRepository {
         Ok(true)
     }
 
-    async fn update_time_unprocessed(&self, job_id: Uuid, time: NaiveDateTime) -> Result<bool, DbErr> {
+    async fn update_time_unprocessed(&self, job_id: Uuid, time: DateTime<Utc>) -> Result<bool, DbErr> {
         let txn = self.db.begin().await?;
 
         let job_metadata_result = job_metadata::Entity::find()
@@ -207,7 +298,8 @@ impl JobRepositoryPort for JobRepository {
 
         if let Some(job) = Job::find_by_id(job_id).one(&txn).await? {
             let mut active_model = job.into_active_model();
-            active_model.time = Set(time);
+            active_model.time = Set(time.into());
+            active_model.scheduled_at = Set(time.into());
             active_model.updated_at = Set(Utc::now().naive_utc());
             active_model.update(&txn).await?;
         }
@@ -215,6 +307,76 @@ impl JobRepositoryPort for JobRepository {
         txn.commit().await?;
         Ok(true)
     }
+
+    async fn postpone_unprocessed<F>(&self, job_id: Uuid, postpone: F) -> Result<Option<DateTime<Utc>>, TempusError>
+    where
+        F: FnOnce(&JobEntity) -> Result<DateTime<Utc>, TempusError> + Send,
+    {
+        let txn = self.db.begin().await?;
+
+        let row = Job::find_by_id(job_id)
+            .find_also_related(job_metadata::Entity)
+            .filter(job_metadata::Column::Status.eq(JobStatusEnum::Scheduled))
+            .lock_exclusive()
+            .one(&txn)
+            .await?;
+        let Some(row) = row else {
+            txn.rollback().await?;
+            return Ok(None);
+        };
+
+        let Some(job) = with_calendars(&txn, vec![row]).await?.pop() else {
+            txn.rollback().await?;
+            return Ok(None);
+        };
+        let time = postpone(&job)?;
+
+        job::ActiveModel {
+            id: Set(job_id),
+            time: Set(time.into()),
+            scheduled_at: Set(time.into()),
+            updated_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+
+        txn.commit().await?;
+        Ok(Some(time))
+    }
+}
+
+/// Loads the calendars referenced by `rows` so working-day rules are always the current ones.
+async fn with_calendars<C: ConnectionTrait>(
+    db: &C,
+    rows: Vec<(job::Model, Option<job_metadata::Model>)>,
+) -> Result<Vec<JobEntity>, DbErr> {
+    let calendar_names: Vec<String> = rows
+        .iter()
+        .filter_map(|(job_model, _)| job_model.calendar.clone())
+        .collect();
+
+    let calendars: HashMap<String, CalendarEntity> = if calendar_names.is_empty() {
+        HashMap::new()
+    } else {
+        business_calendar::Entity::find()
+            .filter(business_calendar::Column::Name.is_in(calendar_names))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|calendar| (calendar.name.clone(), CalendarEntity::from(calendar)))
+            .collect()
+    };
+
+    rows.into_iter()
+        .map(|row| {
+            let calendar = row.0.calendar.as_ref().and_then(|name| calendars.get(name)).cloned();
+            Ok(JobEntity {
+                calendar,
+                ..JobEntity::try_from(row)?
+            })
+        })
+        .collect()
 }
 
 fn to_model_status(status: JobMetadataStatus) -> JobStatusEnum {
@@ -224,5 +386,7 @@ fn to_model_status(status: JobMetadataStatus) -> JobStatusEnum {
         JobMetadataStatus::Completed => JobStatusEnum::Completed,
         JobMetadataStatus::Deleted => JobStatusEnum::Deleted,
         JobMetadataStatus::Failed => JobStatusEnum::Failed,
+        JobMetadataStatus::Skipped => JobStatusEnum::Skipped,
+        JobMetadataStatus::Expired => JobStatusEnum::Expired,
     }
 }
@@ -4,6 +4,10 @@ pub mod prelude;
 
 pub mod job;
 pub mod job_metadata;
+pub mod job_execution;
 pub mod sea_orm_active_enums;
 pub mod job_repository;
-pub mod job_metadata_repository;
\ No newline at end of file
+pub mod job_metadata_repository;
+pub mod job_execution_repository;
+#[cfg(test)]
+pub mod in_memory_job_repository;
@@ -15,12 +15,8 @@ pub enum JobStatusEnum {
     Deleted,
     #[sea_orm(string_value = "failed")]
     Failed,
-}
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "schedule_type_enum")]
-pub enum ScheduleTypeEnum {
-    #[sea_orm(string_value = "http")]
-    Http,
-    #[sea_orm(string_value = "kafka")]
-    Kafka,
+    #[sea_orm(string_value = "skipped")]
+    Skipped,
+    #[sea_orm(string_value = "expired")]
+    Expired,
 }
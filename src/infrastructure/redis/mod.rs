@@ -0,0 +1,2 @@
+pub mod redis_job_executor;
+pub mod redis_publisher;
@@ -0,0 +1,74 @@
+use crate::config::app_config::RedisConfig;
+use crate::domain::job::entity::job_entity::JobEntity;
+use crate::domain::job::entity::job_execution_entity::JobExecutionEntity;
+use crate::domain::job::entity::job_redis_message_entity::JobRedisMessageEntity;
+use crate::domain::job::port::driven::job_executor_port::JobExecutor;
+use crate::domain::job::usecase::create_job_use_case::CreateJobRequest;
+use crate::error::{Result, TempusError};
+use crate::infrastructure::delivery::delivery_headers;
+use crate::infrastructure::metrics::increment_redis_messages;
+use crate::infrastructure::redis::redis_publisher::RedisPublisher;
+use sea_orm::JsonValue;
+use sea_orm::prelude::async_trait::async_trait;
+
+/// Writes `redis` jobs to the stream, channel or list named by their target.
+pub struct RedisJobExecutor {
+    publisher: RedisPublisher,
+}
+
+impl RedisJobExecutor {
+    pub fn new(config: RedisConfig) -> Self {
+        Self {
+            publisher: RedisPublisher::new(config),
+        }
+    }
+}
+
+#[async_trait]
+impl JobExecutor for RedisJobExecutor {
+    fn validate(&self, request: &CreateJobRequest) -> Result<JsonValue> {
+        Ok(serde_json::to_value(JobRedisMessageEntity::from_options(&request.options)?)?)
+    }
+
+    async fn execute(&self, job: &JobEntity, execution: JobExecutionEntity) -> (JobExecutionEntity, Result<()>) {
+        let redis_message = match job.options::<JobRedisMessageEntity>() {
+            Ok(Some(redis_message)) => redis_message,
+            Ok(None) => {
+                let error = TempusError::JobProcessing(format!("Redis job {} has no redis_command", job.id));
+                return (execution, Err(error));
+            }
+            Err(e) => return (execution, Err(e)),
+        };
+
+        let result = self
+            .publisher
+            .publish(&job.target, &job.payload, &redis_message, &delivery_headers(job), job.execution_timeout())
+            .await;
+
+        if result.is_ok() {
+            increment_redis_messages();
+        }
+
+        (execution, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::r#enum::job_enum::JobType;
+
+    #[tokio::test]
+    async fn test_execute_requires_redis_message() {
+        let job = JobEntity::fixture(JobType::REDIS, "orders");
+        let executor = RedisJobExecutor::new(RedisConfig {
+            url: "redis://localhost:6379".to_string(),
+            command_timeout_secs: 1,
+        });
+        let execution = JobExecutionEntity::start(&job, JobType::REDIS, chrono::Utc::now());
+
+        let (_, result) = executor.execute(&job, execution).await;
+
+        assert!(matches!(result, Err(TempusError::JobProcessing(_))));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::config::app_config::RedisConfig;
+use crate::domain::job::entity::job_redis_message_entity::JobRedisMessageEntity;
+use crate::domain::job::r#enum::job_enum::RedisCommand;
+use crate::error::{Result, TempusError};
+use log::{debug, info};
+use redis::aio::ConnectionManager;
+use redis::{Cmd, Value};
+use sea_orm::JsonValue;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+/// Stream entries carry the JSON payload under this field, next to the delivery fields.
+const PAYLOAD_FIELD: &str = "payload";
+
+/// Runs commands over a connection manager, created on first use, that reconnects on its own.
+pub struct RedisPublisher {
+    config: RedisConfig,
+    connection: OnceCell<ConnectionManager>,
+}
+
+impl RedisPublisher {
+    pub fn new(config: RedisConfig) -> Self {
+        Self {
+            config,
+            connection: OnceCell::new(),
+        }
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager> {
+        self.connection
+            .get_or_try_init(|| async {
+                info!("Connecting to Redis");
+                redis::Client::open(self.config.url.as_str())
+                    .map_err(|e| TempusError::Redis(e.to_string()))?
+                    .get_connection_manager()
+                    .await
+                    .map_err(|e| TempusError::Redis(e.to_string()))
+            })
+            .await
+            .cloned()
+    }
+
+    /// `fields` are only written for `xadd`, where they are added to the stream entry.
+    pub async fn publish(
+        &self,
+        key: &str,
+        payload: &JsonValue,
+        redis_message: &JobRedisMessageEntity,
+        fields: &BTreeMap<String, String>,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let timeout = timeout.unwrap_or(self.config.command_timeout());
+        let command = build_command(key, &serde_json::to_string(payload)?, redis_message, fields);
+
+        let reply = tokio::time::timeout(timeout, async {
+            let mut connection = self.connection().await?;
+            command
+                .query_async::<Value>(&mut connection)
+                .await
+                .map_err(|e| TempusError::Redis(e.to_string()))
+        })
+        .await
+        .map_err(|_| TempusError::Timeout(timeout))??;
+
+        debug!("{} {} replied with {:?}", redis_message.command, key, reply);
+        Ok(())
+    }
+}
+
+fn build_command(key: &str, payload: &str, redis_message: &JobRedisMessageEntity, fields: &BTreeMap<String, String>) -> Cmd {
+    let mut command = redis::cmd(&redis_message.command.to_string());
+    command.arg(key);
+
+    match redis_message.command {
+        RedisCommand::Xadd => {
+            if let Some(maxlen) = redis_message.maxlen {
+                command.arg("MAXLEN").arg("~").arg(maxlen);
+            }
+            command.arg("*").arg(PAYLOAD_FIELD).arg(payload);
+            for (name, value) in fields {
+                command.arg(name).arg(value);
+            }
+        }
+        RedisCommand::Publish | RedisCommand::Lpush | RedisCommand::Rpush => {
+            command.arg(payload);
+        }
+    }
+
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::Arg;
+
+    fn args(command: &Cmd) -> Vec<String> {
+        command
+            .args_iter()
+            .map(|arg| match arg {
+                Arg::Simple(arg) => String::from_utf8_lossy(arg).to_string(),
+                Arg::Cursor => "<cursor>".to_string(),
+            })
+            .collect()
+    }
+
+    fn attempt_field() -> BTreeMap<String, String> {
+        BTreeMap::from([("Tempus-Attempt".to_string(), "1".to_string())])
+    }
+
+    #[test]
+    fn test_build_xadd_command() {
+        let redis_message = JobRedisMessageEntity::new(Some("xadd"), Some(1000)).unwrap();
+
+        let command = build_command("orders", r#"{"id":1}"#, &redis_message, &attempt_field());
+
+        assert_eq!(
+            args(&command),
+            ["XADD", "orders", "MAXLEN", "~", "1000", "*", "payload", r#"{"id":1}"#, "Tempus-Attempt", "1"]
+        );
+    }
+
+    #[test]
+    fn test_build_list_and_channel_commands() {
+        let fields = attempt_field();
+
+        for (name, expected) in [("publish", "PUBLISH"), ("lpush", "LPUSH"), ("rpush", "RPUSH")] {
+            let redis_message = JobRedisMessageEntity::new(Some(name), None).unwrap();
+            let command = build_command("orders", r#"{"id":1}"#, &redis_message, &fields);
+
+            assert_eq!(args(&command), [expected, "orders", r#"{"id":1}"#]);
+        }
+    }
+
+    /// Run with `cargo test -- --ignored` against the Redis service in docker-compose.yml.
+    #[tokio::test]
+    #[ignore = "requires a local Redis server"]
+    async fn test_publish_to_local_server() {
+        let config = RedisConfig {
+            url: std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string()),
+            command_timeout_secs: 5,
+        };
+        let publisher = RedisPublisher::new(config);
+        let mut connection = publisher.connection().await.unwrap();
+        let key = format!("tempus-test-{}", uuid::Uuid::new_v4().simple());
+        let payload = serde_json::json!({"event": "order.created"});
+
+        let stream = JobRedisMessageEntity::new(Some("xadd"), Some(10)).unwrap();
+        publisher.publish(&key, &payload, &stream, &BTreeMap::new(), None).await.unwrap();
+        let length: u64 = redis::cmd("XLEN").arg(&key).query_async(&mut connection).await.unwrap();
+        assert_eq!(length, 1);
+
+        let list_key = format!("{}-list", key);
+        let list = JobRedisMessageEntity::new(Some("rpush"), None).unwrap();
+        publisher.publish(&list_key, &payload, &list, &BTreeMap::new(), None).await.unwrap();
+        let message: String = redis::cmd("LPOP").arg(&list_key).query_async(&mut connection).await.unwrap();
+        assert_eq!(serde_json::from_str::<JsonValue>(&message).unwrap(), payload);
+
+        let _: () = redis::cmd("DEL").arg(&key).query_async(&mut connection).await.unwrap();
+    }
+}
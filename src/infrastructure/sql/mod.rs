@@ -0,0 +1 @@
+pub mod sql_job_executor;
\ No newline at end of file